@@ -20,6 +20,14 @@ impl Default for Book {
     }
 }
 
+#[derive(Debug, Clone, Copy, Destructure)]
+#[repr(C)]
+pub struct Point {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
 #[allow(unused)]
 fn destruct(c: &mut Criterion) {
     c.bench_function("destruct", |b| {
@@ -62,5 +70,17 @@ fn mutation(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, destruct, reconstruct, mutation);
+// `into_destruct().freeze()` of a `Copy` structure should compile down to the same copy as the identity.
+#[allow(unused)]
+fn copy_roundtrip(c: &mut Criterion) {
+    let point = Point { x: 1.0, y: 2.0, z: 3.0 };
+    c.bench_function("copy identity", |b| {
+        b.iter(|| criterion::black_box(criterion::black_box(point)));
+    });
+    c.bench_function("copy roundtrip", |b| {
+        b.iter(|| criterion::black_box(criterion::black_box(point).into_destruct().freeze()));
+    });
+}
+
+criterion_group!(benches, destruct, reconstruct, mutation, copy_roundtrip);
 criterion_main!(benches);
\ No newline at end of file
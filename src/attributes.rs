@@ -0,0 +1,672 @@
+use syn::{
+    Attribute,
+    Expr,
+    Field,
+    Ident,
+    Lifetime,
+    LitBool,
+    LitInt,
+    LitStr,
+    Meta,
+    Path,
+    Type,
+    Visibility,
+    ext::IdentExt,
+    parse::Parser,
+    spanned::Spanned,
+};
+
+/// Methods which are not required to go back and forth between the structures,
+/// and therefore can be excluded by `#[destructure(methods(...))]`.
+const OPTIONAL_METHODS: &[&str] = &["reconstruct", "try_reconstruct", "try_reconstruct_all", "edit"];
+
+/// Options given to a structure by `#[destructure(...)]`.
+#[derive(Default)]
+pub(crate) struct ContainerAttributes {
+    /// Whitelist of optional methods to generate. All of them are generated if not specified.
+    pub methods: Option<Vec<Ident>>,
+    /// Generates `set_from_str()` which assigns a field by its name from a string.
+    pub from_str: bool,
+    /// Implements `IntoIterator` for the Destruct structure whose fields all have the same type.
+    pub into_iter: bool,
+    /// Generates `fold()` over the field values of the Destruct structure whose fields all have the same type.
+    pub fold: bool,
+    /// Emits the generated items with only the bounds declared on the original structure.
+    pub bound_relaxed: bool,
+    /// Generates `as_mut_view()` borrowing the fields of the Destruct structure mutably.
+    pub as_mut_view: bool,
+    /// Implements `Debug` for the Destruct structure, leaving out the skipped fields.
+    pub debug: bool,
+    /// Implements `From` between the original structure and the generated structure, instead of only the inherent methods.
+    pub from: bool,
+    /// Implements `Clone` for the Destruct structure bounding the field types instead of the type parameters.
+    pub clone: bool,
+    /// Generates `take_<field>()` on the Destruct structure moving each field out by `std::mem::take`.
+    pub take: bool,
+    /// Generates `encoded_size_hint()` summing the sizes of the field types.
+    pub size_hint: bool,
+    /// Generates `checksum()` hashing the fields into a `u64` by `DefaultHasher`.
+    pub checksum: bool,
+    /// Generates `reflect()` describing each field with its type name and `Display` value.
+    pub reflect: bool,
+    /// Generates `Destruct{Name}FieldValue` holding the value of any field, and `set()` assigning it by the name of the field.
+    pub field_enum: bool,
+    /// Decomposes a structure with a single field into the value of that field, without a Destruct structure.
+    pub transparent: bool,
+    /// Generates `map_all()` converting every field of a single type parameter into another type.
+    pub map_all: bool,
+    /// Error type of `try_freeze()`, into which the errors of each `try_from` field are converted.
+    pub error: Option<Type>,
+    /// Generates `reconstruct_tracked()` recording the names of the fields changed by the closure.
+    pub tracked: bool,
+    /// Generates `into_destruct_boxed()` and `freeze_boxed()` for structures held in a `Box`.
+    pub boxed: bool,
+    /// Generates `borrow_destruct()` borrowing the fields as `Destruct{Name}Ref`, as `DestructureRef` does.
+    pub borrow_view: bool,
+    /// Template of the `Display` implementation for the Destruct structure, e.g. `"{name} by {author}"`.
+    pub display: Option<LitStr>,
+    /// Derives `serde` for the Destruct structure and generates the conversions from and into `serde_json::Value`.
+    pub json: bool,
+    /// Generates `schema()` pairing the name of each field with the name of its type.
+    pub schema: bool,
+    /// Generates the associated constants `FIELD_COUNT` and `FIELD_NAMES` on the Destruct structure.
+    pub field_names: bool,
+    /// Generates `merge_from_ref()` cloning the values borrowed by `Destruct{Name}Ref` into the original structure.
+    pub merge_from_ref: bool,
+    /// Implements `TryFrom<HashMap<String, String>>` for the Destruct structure, parsing each field by `FromStr`.
+    pub from_string_map: bool,
+    /// Invariants on the Destruct structure checked by `debug_assert!` when restoring the original structure.
+    pub asserts: Vec<Expr>,
+    /// Implements `arbitrary::Arbitrary` for the Destruct structure by generating each field.
+    pub arbitrary: bool,
+    /// External types into which the original structure is converted by `From`.
+    pub adapts: Vec<Adapt>,
+    /// Generates `to_destruct()` returning the Destruct structure by cloning each field.
+    pub by_ref_clone: bool,
+    /// Generates `Destruct{Name}Patch` and `with_defaults()` overlaying it over the `Default` of the original structure.
+    pub with_defaults: bool,
+    /// Generates `replace_with()` rebuilding the value behind `&mut self` from a closure over the Destruct structure.
+    pub replace_with: bool,
+    /// Generates `swap_<field>_with()` on the original structure exchanging a single field with another instance.
+    pub swappable: bool,
+    /// Generates `into_tuple()` and `from_tuple()` on the Destruct structure converting it from and into a tuple of its fields.
+    pub as_tuple: bool,
+    /// Generates `validate_all()` on the Destruct structure running the `validate` function of each field.
+    pub validators: bool,
+    /// Generates `fixture()` and `fixture_with()` on the original structure under `#[cfg(test)]`.
+    pub test_fixture: bool,
+    /// Treats fields with `#[serde(skip)]` or `#[serde(skip_serializing)]` as `#[destructure(skip)]`.
+    pub respect_serde_skip: bool,
+    /// Generates `as_refs()` on the Destruct structure borrowing its fields as a tuple of references.
+    pub as_refs: bool,
+    /// Generates `{Name}Editor` and `edit()` on the original structure staging changes until `commit()`.
+    pub editor: bool,
+    /// Hides the generated structures, and the methods handing them out, from rustdoc by `#[doc(hidden)]`.
+    pub doc_hidden: bool,
+    /// Generates `try_freeze_all()` and `TryFrom<Destruct{Name}>` collecting the errors of every `try_from` field into `{Name}BuildError`.
+    pub build_error: bool,
+    /// Generates the `{name}_helpers` module holding free functions such as `reconstruct_option()`.
+    pub helpers: bool,
+    /// Implements `Default` for the Destruct structure by defaulting each field independently.
+    pub default_fields: bool,
+    /// Generates `diff_patch()` and `apply_patch()` with `Destruct{Name}Patch` holding the changed fields.
+    pub diff_patch: bool,
+    /// Implements `Index<usize>` and `IndexMut<usize>` for the Destruct structure whose fields all have the same type.
+    pub index_usize: bool,
+    /// Keeps every field of the Destruct structure private, generating getters instead, by `pub_fields = false`.
+    pub private_fields: bool,
+    /// Visibility given to every non-skipped field of the Destruct structure instead of `pub`, e.g. `field_visibility = "pub(crate)"`.
+    pub field_visibility: Option<Visibility>,
+    /// Prevents the Destruct structure from being built outside the module by a private field.
+    pub sealed: bool,
+    /// Name of the lifetime of `Destruct{Name}Ref`, e.g. `lifetime = "a"`, instead of the hygienic default.
+    pub lifetime: Option<Lifetime>,
+    /// Constructor called by `freeze()` with the fields in declaration order, instead of a structure literal.
+    pub freeze_via: Option<Path>,
+    /// Fields whose setters must be called on `Destruct{Name}Staged` before it can be frozen, by `staged(required(...))`.
+    pub staged: Option<Vec<Ident>>,
+    /// Generates `into_string_map()` on the Destruct structure converting its fields into strings by `Display`.
+    pub into_string_map: bool,
+    /// How `None` of `Option` fields is represented in the string maps and `set_from_str()`, by `option_as = "..."`.
+    pub option_as: OptionAs,
+    /// Names of the options in the order they are given, to point at those the derive does not use.
+    pub given: Vec<Ident>,
+}
+
+/// Representation of `None` given by `#[destructure(option_as = "skip|empty|null")]`.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OptionAs {
+    /// `None` is left out of the map, and a missing key is read as `None`.
+    #[default]
+    Skip,
+    /// `None` is an empty string.
+    Empty,
+    /// `None` is the string `null`.
+    Null,
+}
+
+/// An external type given by `#[destructure(adapt(target = "...", map(...)))]`.
+pub(crate) struct Adapt {
+    /// Type implementing `From` for the original structure.
+    pub target: Path,
+    /// Pairs of a field of the Destruct structure and the field of `target` it is assigned to.
+    pub map: Vec<(Ident, Ident)>,
+}
+
+impl ContainerAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+        for attr in destructure_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if let Some(ident) = meta.path.get_ident() {
+                    parsed.given.push(ident.clone());
+                }
+
+                if meta.path.is_ident("methods") {
+                    let methods = parsed.methods.get_or_insert_with(Vec::new);
+                    return meta.parse_nested_meta(|method| {
+                        let ident = method.path.require_ident()?;
+                        if !OPTIONAL_METHODS.iter().any(|optional| ident == optional) {
+                            return Err(method.error(format!(
+                                "unknown method `{}`, expected one of: {}.",
+                                ident, OPTIONAL_METHODS.join(", ")
+                            )))
+                        }
+                        methods.push(ident.clone());
+                        Ok(())
+                    })
+                }
+
+                if meta.path.is_ident("adapt") {
+                    let mut target = None;
+                    let mut map = Vec::new();
+                    meta.parse_nested_meta(|adapt| {
+                        if adapt.path.is_ident("target") {
+                            let lit: LitStr = adapt.value()?.parse()?;
+                            target = Some(lit.parse()?);
+                            return Ok(())
+                        }
+
+                        if adapt.path.is_ident("map") {
+                            return adapt.parse_nested_meta(|pair| {
+                                let from = pair.path.require_ident()?.clone();
+                                let lit: LitStr = pair.value()?.parse()?;
+                                map.push((from, parse_field_ident(&lit)?));
+                                Ok(())
+                            })
+                        }
+
+                        Err(adapt.error("unsupported `adapt` option, expected `target` or `map`."))
+                    })?;
+                    let target = target.ok_or_else(|| meta.error("`adapt` requires `target = \"...\"`."))?;
+                    parsed.adapts.push(Adapt { target, map });
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("staged") {
+                    let required = parsed.staged.get_or_insert_with(Vec::new);
+                    meta.parse_nested_meta(|staged| {
+                        if staged.path.is_ident("required") {
+                            return staged.parse_nested_meta(|field| {
+                                required.push(field.path.require_ident()?.clone());
+                                Ok(())
+                            })
+                        }
+
+                        Err(staged.error("unsupported `staged` option, expected `required`."))
+                    })?;
+                    if required.is_empty() {
+                        return Err(meta.error("`staged` requires at least one field in `required(...)`."))
+                    }
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("pub_fields") {
+                    let lit: LitBool = meta.value()?.parse()?;
+                    parsed.private_fields = !lit.value;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("field_visibility") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.field_visibility = Some(lit.parse().map_err(|_| syn::Error::new(
+                        lit.span(),
+                        format!("`{}` is not a valid visibility.", lit.value())
+                    ))?);
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("option_as") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.option_as = match lit.value().as_str() {
+                        "skip" => OptionAs::Skip,
+                        "empty" => OptionAs::Empty,
+                        "null" => OptionAs::Null,
+                        other => return Err(syn::Error::new(
+                            lit.span(),
+                            format!("unsupported `option_as` policy `{}`, expected one of: skip, empty, null.", other)
+                        ))
+                    };
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("lifetime") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    let name = lit.value();
+                    let name = name.strip_prefix('\'').unwrap_or(&name);
+                    if syn::parse_str::<Ident>(name).is_err() {
+                        return Err(syn::Error::new(lit.span(), format!("`{}` is not a valid lifetime name.", name)))
+                    }
+                    parsed.lifetime = Some(Lifetime::new(&format!("'{}", name), lit.span()));
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("freeze_via") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.freeze_via = Some(lit.parse().map_err(|_| syn::Error::new(
+                        lit.span(),
+                        format!("`{}` is not a valid path to a function.", lit.value())
+                    ))?);
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("display") {
+                    parsed.display = Some(meta.value()?.parse()?);
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("assert") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.asserts.push(lit.parse()?);
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("error") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.error = Some(lit.parse()?);
+                    return Ok(())
+                }
+
+                let flags = [
+                    ("from_str", &mut parsed.from_str),
+                    ("into_iter", &mut parsed.into_iter),
+                    ("fold", &mut parsed.fold),
+                    ("bound_relaxed", &mut parsed.bound_relaxed),
+                    ("as_mut_view", &mut parsed.as_mut_view),
+                    ("debug", &mut parsed.debug),
+                    ("from", &mut parsed.from),
+                    ("clone", &mut parsed.clone),
+                    ("take", &mut parsed.take),
+                    ("replace_with", &mut parsed.replace_with),
+                    ("swappable", &mut parsed.swappable),
+                    ("as_tuple", &mut parsed.as_tuple),
+                    ("as_refs", &mut parsed.as_refs),
+                    ("editor", &mut parsed.editor),
+                    ("doc_hidden", &mut parsed.doc_hidden),
+                    ("build_error", &mut parsed.build_error),
+                    ("helpers", &mut parsed.helpers),
+                    ("validators", &mut parsed.validators),
+                    ("test_fixture", &mut parsed.test_fixture),
+                    ("respect_serde_skip", &mut parsed.respect_serde_skip),
+                    ("size_hint", &mut parsed.size_hint),
+                    ("checksum", &mut parsed.checksum),
+                    ("reflect", &mut parsed.reflect),
+                    ("field_enum", &mut parsed.field_enum),
+                    ("transparent", &mut parsed.transparent),
+                    ("map_all", &mut parsed.map_all),
+                    ("tracked", &mut parsed.tracked),
+                    ("boxed", &mut parsed.boxed),
+                    ("borrow_view", &mut parsed.borrow_view),
+                    ("json", &mut parsed.json),
+                    ("schema", &mut parsed.schema),
+                    ("field_names", &mut parsed.field_names),
+                    ("merge_from_ref", &mut parsed.merge_from_ref),
+                    ("from_string_map", &mut parsed.from_string_map),
+                    ("into_string_map", &mut parsed.into_string_map),
+                    ("arbitrary", &mut parsed.arbitrary),
+                    ("by_ref_clone", &mut parsed.by_ref_clone),
+                    ("with_defaults", &mut parsed.with_defaults),
+                    ("default_fields", &mut parsed.default_fields),
+                    ("diff_patch", &mut parsed.diff_patch),
+                    ("index_usize", &mut parsed.index_usize),
+                    ("sealed", &mut parsed.sealed),
+                ];
+                if let Some((_, flag)) = flags.into_iter().find(|(key, _)| meta.path.is_ident(key)) {
+                    *flag = true;
+                    return Ok(())
+                }
+
+                Err(meta.error("unsupported `destructure` attribute."))
+            })?;
+        }
+        Ok(parsed)
+    }
+
+    /// Returns the first option given to the structure which is not in `used`.
+    pub fn unused(&self, used: &[&str]) -> Option<&Ident> {
+        self.given.iter().find(|option| !used.iter().any(|used| *option == used))
+    }
+
+    /// Whether the optional method `name` should be generated.
+    pub fn generates(&self, name: &str) -> bool {
+        self.methods.as_ref()
+            .map(|methods| methods.iter().any(|method| method == name))
+            .unwrap_or(true)
+    }
+}
+
+/// Options given to a structure by `#[mutation(...)]`.
+#[derive(Default)]
+pub(crate) struct MutationAttributes {
+    /// Generates `async_try_substitute()` awaiting the future returned by the closure.
+    pub asynchronous: bool,
+    /// Generates `fields_mut()` borrowing every field of the same type as an array.
+    pub array: bool,
+    /// Generates `substitute_pinned()` projecting `Pin<&mut Self>` onto the fields.
+    pub pin: bool,
+}
+
+impl MutationAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("mutation")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("async") {
+                    parsed.asynchronous = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("array") {
+                    parsed.array = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("pin") {
+                    parsed.pin = true;
+                    return Ok(())
+                }
+
+                Err(meta.error("unsupported `mutation` attribute."))
+            })?;
+        }
+        Ok(parsed)
+    }
+}
+
+/// Options given to a single field by `#[mutation(...)]`.
+#[derive(Default)]
+pub(crate) struct MutationFieldAttributes {
+    /// Callback notified with the new value when the field is changed by the substitution.
+    pub on_change: Option<Path>,
+    /// Name of the partial view generated by `substitute_<group>()` exposing the field.
+    pub group: Option<Ident>,
+    /// Projects the field structurally pinned, as `Pin<&mut T>`, by `substitute_pinned()`.
+    pub pinned: bool,
+}
+
+impl MutationFieldAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("mutation")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("on_change") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.on_change = Some(lit.parse()?);
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("group") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.group = Some(parse_field_ident(&lit)?);
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("pinned") {
+                    parsed.pinned = true;
+                    return Ok(())
+                }
+
+                Err(meta.error("unsupported `mutation` field attribute."))
+            })?;
+        }
+        Ok(parsed)
+    }
+}
+
+/// Options given to a single field by `#[destructure(...)]`.
+#[derive(Default)]
+pub(crate) struct FieldAttributes {
+    /// Name used for this field in the generated structure instead of the original one.
+    pub rename: Option<Ident>,
+    /// Keeps the field private in the generated structure.
+    pub skip: bool,
+    /// Predicate on the Destruct structure which hides the field from `visible_fields()` when it holds.
+    pub skip_if: Option<Path>,
+    /// Function checking the field by `validate_all()` of the Destruct structure, given by `validate = "path::to::fn"`.
+    pub validate: Option<Path>,
+    /// Shows the field as `<redacted>` in the generated `Debug`.
+    pub redact: bool,
+    /// Type exposed in the Destruct structure, from which the field is converted back by `TryFrom` on `try_freeze()`.
+    pub try_from: Option<Type>,
+    /// Leaves the field out of the generated `PartialEq`.
+    pub eq_skip: bool,
+    /// Clones the value behind the `Arc` or `Rc` of the field in the generated `Clone`, instead of sharing it.
+    pub deep_clone: bool,
+    /// Marks the field as the identity of the entity, which alone is compared and hashed.
+    pub id: bool,
+    /// Marks the field as the only one compared by the generated `Ord`.
+    pub sort_key: bool,
+    /// Name of the field of the Destruct structure gathering this field into a sub-structure.
+    pub group: Option<Ident>,
+    /// Flattens the sub-structure of the group into the Destruct structure by `#[serde(flatten)]` with `json`.
+    pub serde: bool,
+    /// Leaves the field out of the Destruct structure, dropping it on `into_destruct()`.
+    pub skip_drop: bool,
+    /// Expression initializing the `skip_drop` field again on `freeze()`.
+    pub init: Option<Expr>,
+    /// Position of the field in the generated structure, placed before the fields without it.
+    pub order: Option<LitInt>,
+    /// Generates `<field>_cow()` on the original structure borrowing the field as a `Cow`.
+    pub cow_accessor: bool,
+    /// Generates `unsafe fn set_<field>()` on the Destruct structure overriding the skipped field.
+    pub unsafe_set: bool,
+    /// Generates `push_<field>()` or `insert_<field>()` on the Destruct structure for a `Vec`, `HashSet` or `HashMap` field.
+    pub collection: bool,
+    /// Generates `map_<field>()` on the original structure changing the type parameter carried by the field.
+    pub generic_field: bool,
+    /// Wraps the field of the Destruct structure in `zeroize::Zeroizing`, wiping it on drop.
+    pub zeroize: bool,
+    /// Holds a copy of the field in `Destruct{Name}Ref` instead of a reference.
+    pub copy: bool,
+    /// Type of the field wrapped by `zeroize`, resolved once the type of the field is known.
+    pub zeroized: Option<Type>,
+}
+
+impl FieldAttributes {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+        let mut init_span = None;
+        let mut unsafe_set_span = None;
+        for attr in destructure_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip_drop") {
+                    parsed.skip_drop = true;
+                    init_span.get_or_insert(meta.path.span());
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("init") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.init = Some(lit.parse()?);
+                    init_span.get_or_insert(lit.span());
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("rename") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.rename = Some(parse_field_ident(&lit)?);
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("order") {
+                    let lit: LitInt = meta.value()?.parse()?;
+                    lit.base10_parse::<usize>()?;
+                    parsed.order = Some(lit);
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("skip") {
+                    parsed.skip = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("redact") {
+                    parsed.redact = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("unsafe_set") {
+                    parsed.unsafe_set = true;
+                    unsafe_set_span = Some(meta.path.span());
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("collection") {
+                    parsed.collection = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("copy") {
+                    parsed.copy = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("zeroize") {
+                    parsed.zeroize = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("generic_field") {
+                    parsed.generic_field = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("cow_accessor") {
+                    parsed.cow_accessor = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("eq_skip") {
+                    parsed.eq_skip = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("deep_clone") {
+                    parsed.deep_clone = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("sort_key") {
+                    parsed.sort_key = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("id") {
+                    parsed.id = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("group") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.group = Some(parse_field_ident(&lit)?);
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("serde") {
+                    parsed.serde = true;
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("try_from") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.try_from = Some(lit.parse()?);
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("skip_if") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.skip_if = Some(lit.parse()?);
+                    return Ok(())
+                }
+
+                if meta.path.is_ident("validate") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    parsed.validate = Some(lit.parse()?);
+                    return Ok(())
+                }
+
+                Err(meta.error("unsupported `destructure` field attribute."))
+            })?;
+        }
+        if let Some(span) = unsafe_set_span.filter(|_| !parsed.skip) {
+            return Err(syn::Error::new(span, "`unsafe_set` can only be used with `skip`, since the other fields are public."))
+        }
+        if let Some(span) = init_span {
+            match (parsed.skip_drop, &parsed.init) {
+                (true, None) => return Err(syn::Error::new(span, "`skip_drop` requires `init = \"...\"` to initialize the field again on `freeze()`.")),
+                (false, Some(_)) => return Err(syn::Error::new(span, "`init` can only be used with `skip_drop`.")),
+                _ => {}
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+/// Iterates over `#[destructure(...)]` attributes.
+///
+/// A bare `#[destructure]` has no options to give, so it is skipped as if it were absent.
+fn destructure_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter()
+        .filter(|attr| attr.path().is_ident("destructure"))
+        .filter(|attr| !matches!(attr.meta, Meta::Path(_)))
+}
+
+/// Parses a string literal as a field name of the generated structure.
+///
+/// Reserved keywords are rejected unless they are written as raw identifiers (e.g. `r#match`),
+/// since they cannot be used as a field name as is.
+fn parse_field_ident(lit: &LitStr) -> syn::Result<Ident> {
+    let value = lit.value();
+    match syn::parse_str::<Ident>(&value) {
+        Ok(mut ident) => {
+            ident.set_span(lit.span());
+            Ok(ident)
+        },
+        Err(_) if Ident::parse_any.parse_str(&value).is_ok() => Err(syn::Error::new(
+            lit.span(),
+            format!("`{value}` is a reserved keyword and cannot be used as a field name, use `r#{value}` instead.")
+        )),
+        Err(_) => Err(syn::Error::new(lit.span(), format!("`{value}` is not a valid field name."))),
+    }
+}
+
+// Every generated item must agree on the effective name and type of a field,
+// so they are only ever resolved by the following functions.
+
+/// Resolves the name of a field in the generated structure.
+pub(crate) fn destruct_ident<'a>(field: &'a Field, attrs: &'a FieldAttributes) -> &'a Ident {
+    attrs.rename.as_ref().unwrap_or_else(|| field.ident.as_ref().expect("only named fields have a destruct name"))
+}
+
+/// Resolves the name of a field in the generated structure as a string key,
+/// used wherever fields are looked up by name at runtime (e.g. `from_str`).
+pub(crate) fn destruct_key(field: &Field, attrs: &FieldAttributes) -> String {
+    destruct_ident(field, attrs).unraw().to_string()
+}
+
+/// Resolves the type of a field in the generated structure.
+pub(crate) fn destruct_type<'a>(field: &'a Field, attrs: &'a FieldAttributes) -> &'a Type {
+    attrs.zeroized.as_ref()
+        .or(attrs.try_from.as_ref())
+        .unwrap_or(&field.ty)
+}
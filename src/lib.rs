@@ -147,96 +147,3264 @@
 //! }
 //! ```
 
+mod attributes;
+
 use proc_macro::TokenStream;
-use quote::{quote, quote_spanned};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::{
     parse_macro_input,
+    Attribute,
+    ConstParam,
     DeriveInput,
     Ident,
     Data,
     DataStruct,
+    Field,
     Fields,
     FieldsNamed, 
+    GenericParam,
+    Generics,
     Lifetime, 
+    LifetimeParam,
+    LitStr,
+    Meta,
+    Path,
+    PredicateType,
+    Token,
+    Type,
+    TypeParam,
+    WherePredicate,
+    parse_quote,
+    ext::IdentExt,
+    parse::Parser,
+    punctuated::Punctuated,
     spanned::Spanned
 };
 
-/// Automatically implements `into_destruct()` and `freeze()` methods.
+use crate::attributes::{Adapt, ContainerAttributes, FieldAttributes, MutationAttributes, MutationFieldAttributes, OptionAs, destruct_ident, destruct_key, destruct_type};
+
+/// Automatically implements `into_destruct()` and `freeze()` methods.
+///
+/// ## Attributes
+/// A bare `#[destructure]` without any options is accepted on both the structure and its fields,
+/// and behaves the same as if it were absent.
+///
+/// - `#[destructure(methods(reconstruct, try_reconstruct, try_reconstruct_all, edit))]`: Generates only the listed optional methods.
+///   `into_destruct()` and `freeze()` are always generated. `reconstruct_with_ctx()` follows `reconstruct`,
+///   and `try_reconstruct_ctx()` (along with `Destruct{Name}ReconstructError`) follows `try_reconstruct`.
+///   Methods not listed are not emitted at all, so no code (including the glue monomorphized
+///   for each closure passed to them) ends up in the binary.
+///   This is useful on embedded targets where the type is only ever used through `into_destruct()`.
+/// - `#[destructure(helpers)]`: Generates a `book_helpers` module (named after the structure in `snake_case`)
+///   next to the structure, holding `reconstruct_option(opt, f)` which reconstructs the value of an `&mut Option<Book>`
+///   in place and does nothing if it is `None`. Since the module refers to its parent by `use super::*`,
+///   the structure must be declared at module level rather than inside a function.
+/// - `#[destructure(from_str)]`: Generates `set_from_str(&mut self, field: &str, value: &str)`
+///   on the Destruct structure, which parses `value` into the field named `field` using [`FromStr`](std::str::FromStr).
+///   Every field type must implement `FromStr` with an error implementing `Display`.
+/// - `#[destructure(from_string_map)]`: Implements `TryFrom<HashMap<String, String>>` for the Destruct structure,
+///   parsing each field from the value of its name by `FromStr`. It fails on missing, unknown or unparsable fields.
+///   Skipped fields cannot be given by the map and are initialized by `Default` instead.
+/// - `#[destructure(into_string_map)]`: Generates `into_string_map(self)` on the Destruct structure, converting
+///   the non-skipped fields into a `HashMap<String, String>` by `Display`, keyed by their names.
+/// - `#[destructure(option_as = "skip")]`: Chooses how `None` of `Option<T>` fields is represented by `into_string_map`,
+///   `from_string_map` and `from_str`, which convert the `T` inside. With `skip` (the default), `None` is left out of the map
+///   and a missing key is read as `None`. With `empty` or `null`, it is an empty string or the string `null` respectively.
+/// - `#[destructure(into_iter)]`: Implements `IntoIterator` for the Destruct structure,
+///   yielding the field values in declaration order, and for `&DestructBook`, yielding references to them,
+///   so that `for value in &des` does not consume it. All fields must have the same type.
+/// - `#[destructure(index_usize)]`: Implements `Index<usize>` and `IndexMut<usize>` for the Destruct structure,
+///   mapping `0` to the first field and so on in declaration order, and panicking out of range.
+///   All non-skipped fields must have the same type.
+/// - `#[destructure(fold)]`: Generates `fold(self, init, f)` on the Destruct structure,
+///   folding the field values in declaration order. All fields must have the same type.
+/// - `#[destructure(sealed)]`: Gives the Destruct structure a private field, so that it cannot be built by a struct literal
+///   outside the module and only arises from `into_destruct()`. Its public fields can still be read and modified.
+/// - `#[destructure(pub_fields = false)]`: Keeps every field of the Destruct structure private,
+///   so that it can only be constructed and matched inside the module, and generates `<field>(&self)` getters
+///   on the Destruct structure for the non-skipped fields instead. It cannot be combined with `group`.
+/// - `#[destructure(field_visibility = "pub(crate)")]`: Gives every non-skipped field of the Destruct structure
+///   (and of its groups) the visibility instead of `pub`, while the structure itself stays `pub`.
+///   It cannot be combined with `pub_fields = false`.
+/// - `#[destructure(bound_relaxed)]`: Emits the generated structure and impls with exactly the generics
+///   and bounds declared on the original structure (including `?Sized`), without adding the bounds
+///   some options require (e.g. `FromStr` for `from_str`). Those bounds must then be declared by hand.
+/// - `#[destructure(as_mut_view)]`: Generates `as_mut_view(&mut self)` on the Destruct structure,
+///   returning a `Destruct{Name}Mut` which holds mutable references to its fields,
+///   in the same manner as [`Mutation`] does for the original structure.
+/// - `#[destructure(debug)]`: Implements `Debug` for the Destruct structure.
+///   Skipped fields are left out, unless they are marked with `redact`.
+/// - `#[destructure(from)]`: Implements `From<Book>` for the Destruct structure and `From<DestructBook>` for the original
+///   structure (or `TryFrom` with `try_from` fields), so that the conversions work with `.into()` in generic code.
+///   With `borrow_view`, `From<&Book>` is also implemented for `Destruct{Name}Ref`, as with [`DestructureRef`].
+/// - `#[destructure(clone)]`: Implements `Clone` for the Destruct structure, bounding each field type by `Clone`
+///   instead of each type parameter as `#[derive(Clone)]` does, so that fields such as `PhantomData<T>`
+///   do not require `T: Clone`. It cannot be combined with `group`.
+/// - `#[destructure(take)]`: Generates `take_<field>(&mut self)` on the Destruct structure for each non-skipped field,
+///   moving the value out by [`std::mem::take`] and leaving its `Default`. The non-skipped field types must implement `Default`.
+///   Grouped fields are not covered.
+/// - `#[destructure(replace_with)]`: Generates `replace_with(&mut self, f)` on the original structure, which passes the current
+///   fields to `f` as the Destruct structure and freezes the one it returns back into `*self`, without owning the value.
+///   The value is taken out by [`std::mem::take`], so that a panic in `f` leaves `Default` behind.
+///   The original structure must implement `Default`, and it cannot be used with `try_from` fields.
+/// - `#[destructure(swappable)]`: Generates `swap_<field>_with(&mut self, other: &mut Book)` on the original structure
+///   for each non-skipped field, exchanging only that field between the two instances by [`std::mem::swap`].
+/// - `#[destructure(as_tuple)]`: Generates `into_tuple(self)` and `from_tuple(tuple)` on the Destruct structure,
+///   converting it from and into a tuple of the non-skipped fields in field order, e.g. for tuple-based APIs.
+///   `From<DestructBook>` is also implemented for the tuple, so that `let (id, name) = des.into();` works.
+///   `from_tuple()` fills skipped fields by `Default`, so their types must implement it.
+///   It cannot be combined with `group` or `#[cfg]` fields.
+/// - `#[destructure(test_fixture)]`: Generates, under `#[cfg(test)]`, `fixture()` on the original structure building it
+///   with every field set to its `Default`, and `fixture_with(f)` overriding some fields of the fixture in the same manner as `reconstruct()`.
+///   Every field type must implement `Default` except the `skip_drop` fields, which are filled by their `init`,
+///   and it cannot be used with `try_from` fields.
+/// - `#[destructure(respect_serde_skip)]`: Treats the fields marked with `#[serde(skip)]` or `#[serde(skip_serializing)]`
+///   as if they were marked with `#[destructure(skip)]`, so that serde-heavy structures need not annotate them twice.
+/// - `#[destructure(as_refs)]`: Generates `as_refs(&self)` on the Destruct structure, borrowing the non-skipped fields
+///   as a tuple of references in field order, e.g. `let (id, name) = des.as_refs();`.
+///   It cannot be combined with `group` or `#[cfg]` fields.
+/// - `#[destructure(editor)]`: Generates `edit(&mut self)` on the original structure, returning a `BookEditor` which borrows it
+///   and has `set_<field>(value)` for each non-skipped field. The values set are staged in the editor, which can be passed
+///   around and set over time, and written back at once by `commit()`, which also ends a chain of setters
+///   such as `book.edit().set_name(name).commit()`. Dropping the editor discards them.
+/// - `#[destructure(doc_hidden)]`: Attaches `#[doc(hidden)]` to the Destruct structure (with its sub-structures
+///   and the other generated types, such as `DestructBookReconstructError`) and to the methods of the original structure such as `into_destruct()`, so that they stay usable but
+///   are left out of rustdoc, e.g. when the Destruct structure is an implementation detail. It also applies to `DestructureRef`.
+/// - `#[destructure(size_hint)]`: Generates `encoded_size_hint(&self)` on the Destruct structure,
+///   returning the sum of [`size_of`](std::mem::size_of) of the non-skipped field types.
+///   This is exact for plain `Copy` fields and helps to preallocate buffers before encoding.
+/// - `#[destructure(checksum)]`: Generates `checksum(&self) -> u64` on the Destruct structure, feeding the non-skipped
+///   fields into a [`DefaultHasher`](std::collections::hash_map::DefaultHasher) in order, as a cheap fingerprint
+///   for caching or dirty tracking. The field types must implement `Hash`.
+/// - `#[destructure(transparent)]`: For a structure with exactly one non-skipped field (including tuple structures
+///   such as `struct Id(Uuid)`), `into_destruct()` returns the value of that field as is and `from_destruct()`
+///   wraps it again, instead of going through a Destruct structure. Skipped fields are filled with `Default::default()`
+///   by `from_destruct()`, while `reconstruct()` keeps their values. The only other option it accepts is `methods`.
+/// - `#[destructure(map_all)]`: For a structure with a single type parameter `T` which is the type of every field
+///   (e.g. `struct Point<T> { x: T, y: T }`), generates `map_all(self, f)` on the Destruct structure,
+///   converting `DestructPoint<T>` into `DestructPoint<U>` by applying `f` to each field.
+///   The bounds declared on `T` are also required on `U`.
+/// - `#[destructure(error = "BookError")]`: Error type of `try_freeze()` when fields are given `try_from`.
+///   The error of each conversion must be convertible into it by `From`.
+///   It can be omitted when only a single field is given `try_from`, in which case its error is used as is.
+/// - `#[destructure(build_error)]`: With `try_from` fields, generates `try_freeze_all()` on the Destruct structure,
+///   which attempts every conversion and fails with a `BookBuildError` listing each failed field as a variant
+///   of `BookBuildFailure` holding its error, instead of stopping at the first one as `try_freeze()` does.
+///   `TryFrom<DestructBook>` is implemented for the original structure by it, so it cannot be combined with `from`.
+///   The errors of the conversions must implement `Debug`, and `error` is still required by `try_freeze()` with several
+///   `try_from` fields. Not supported on generic structures.
+/// - `#[destructure(tracked)]`: Generates `reconstruct_tracked(self, f, sink)`, which behaves like `reconstruct()`
+///   but, in builds with `debug_assertions`, pushes the names of the fields changed by `f` into `sink`.
+///   Every field type must implement `Clone` and `PartialEq`. It cannot be combined with `try_from` fields.
+/// - `#[destructure(by_ref_clone)]`: Generates `to_destruct(&self)`, which returns the Destruct structure
+///   by cloning each field instead of consuming the original structure. Every field type must implement `Clone`.
+/// - `#[destructure(boxed)]`: Generates `into_destruct_boxed(self: Box<Self>)` and `freeze_boxed()`
+///   (or `try_freeze_boxed()` with `try_from` fields), which unbox and rebox the original structure.
+/// - `#[destructure(display = "{name} by {author}")]`: Implements `Display` for the Destruct structure,
+///   interpolating the fields named by the placeholders (after `rename`) into the template.
+///   Format specs such as `{name:>8}` are allowed. Placeholders naming skipped or nonexistent fields are rejected.
+/// - `#[destructure(assert = "self.age < 150")]`: Checks the invariant on the Destruct structure by `debug_assert!`
+///   before restoring the original structure in `freeze()` (or `try_freeze()`), catching violations in debug builds
+///   without any cost in release builds. It can be given several times. Since `freeze()`, `reconstruct()`
+///   and their fallible variants are `#[track_caller]`, a violation is reported at the caller.
+/// - `#[destructure(freeze_via = "Book::assemble")]`: `freeze()` (or `try_freeze()`) calls `Book::assemble(...)`
+///   with the fields in declaration order, instead of building the original structure by a structure literal,
+///   so that rebuilds go through a validated constructor. It cannot be combined with `transparent`.
+/// - `#[destructure(with_defaults)]`: Generates `Destruct{Name}Patch`, holding each public field as an `Option`,
+///   and `with_defaults(patch)` on the Destruct structure, which starts from the `Default` of the original structure
+///   and overrides the fields given as `Some`, e.g. for loading configurations. The original structure must implement `Default`.
+/// - `#[destructure(diff_patch)]`: Generates `diff_patch(&self, other)` on the Destruct structure, returning
+///   `Destruct{Name}Patch` where only the fields changed in `other` are `Some`, and `apply_patch(&mut self, patch)`
+///   applying it, e.g. to sync versions elsewhere. The public field types must implement `PartialEq` and `Clone`.
+/// - `#[destructure(default_fields)]`: Implements `Default` for the Destruct structure by defaulting each field
+///   independently, so a blank Destruct structure can be built up even if the original structure does not implement `Default`.
+///   Every field type must implement `Default`. It cannot be combined with `group`.
+/// - `#[destructure(schema)]`: Generates `schema()` on the Destruct structure, which lists the name of each field
+///   paired with `std::any::type_name` of its type, e.g. for generating documentation. Skipped fields are excluded.
+/// - `#[destructure(reflect)]`: Generates `reflect()` on the Destruct structure, returning a `Destruct{Name}FieldMeta`
+///   with the name, `std::any::type_name` and `Display` value of each field, e.g. for rendering a settings editor
+///   without a serialization framework. The public field types must implement `Display`. Skipped fields are excluded.
+/// - `#[destructure(field_enum)]`: Generates `Destruct{Name}FieldValue`, an enum with a variant per public field
+///   (e.g. `Name(String)`), and `set(&mut self, name, value)` on the Destruct structure assigning it by the name of the field.
+///   It returns `Destruct{Name}SetError` if there is no such field or the variant does not match the field,
+///   e.g. for dynamic editors. Not supported on generic structures.
+/// - `#[destructure(field_names)]`: Generates the associated constants `FIELD_COUNT` and `FIELD_NAMES` on the Destruct structure,
+///   counting and naming the non-skipped fields. Since they are constants, `[T; DestructBook::FIELD_COUNT]` can size a buffer.
+/// - `#[destructure(json)]`: Derives `serde::Serialize` and `serde::Deserialize` for the Destruct structure,
+///   and generates `from_json_value()` and `to_json_value()` converting it from and into `serde_json::Value`.
+///   Requires the `serde_json` feature, and `serde` (with `derive`) and `serde_json` as dependencies of the user crate.
+///   Skipped fields are given `#[serde(skip)]`, so they are left out of the JSON and filled by `Default` when deserializing.
+/// - `#[destructure(arbitrary)]`: Implements `arbitrary::Arbitrary` for the Destruct structure,
+///   generating each field by its own `Arbitrary` implementation, e.g. for fuzzing or property testing.
+///   Requires the `arbitrary` feature, and `arbitrary` as a dependency of the user crate.
+/// - `#[destructure(adapt(target = "other::Dto", map(id = "identifier")))]`: Implements `From<Book>` for `other::Dto`,
+///   assigning each public field of the Destruct structure to the field of the same name, or to the one given by `map`.
+///   Skipped fields are not assigned. It can be given several times for different targets.
+///   Like the placeholders of `display`, the fields named in `map` must exist and not be skipped,
+///   so that renaming or removing a field fails to compile instead of being ignored.
+/// - `#[destructure(staged(required(name, author)))]`: Generates `Destruct{Name}Staged`, wrapping the Destruct structure
+///   with a type state per required field, and `into_staged()` and `reconstruct_staged(f)` on the original structure.
+///   `freeze()` (or `try_freeze()`) of the staged structure can only be called after `set_name()` and `set_author()`,
+///   so the required edits are checked at compile time. The other fields are reached through `destruct_mut()`.
+///   Not supported on generic structures.
+/// - `#[destructure(borrow_view)]`: Generates `borrow_destruct(&self)` returning `Destruct{Name}Ref`,
+///   the same structure as [`DestructureRef`] generates, whose fields are named after those of the Destruct structure.
+///   It replaces deriving `DestructureRef`, so the two cannot be used together. It cannot be combined with `group`.
+///   `#[destructure(lifetime = "a")]` names the lifetime of `Destruct{Name}Ref`, as with [`DestructureRef`].
+///   `lifetime` and `merge_from_ref` are rejected without `borrow_view`, since they only apply to `Destruct{Name}Ref`.
+///
+/// ## Field Attributes
+/// - `#[destructure(rename = "name")]`: Uses `name` as the field name in the generated structure.
+///   Reserved keywords must be written as raw identifiers (e.g. `r#match`).
+/// - `#[destructure(skip)]`: Keeps the field private in the generated structure,
+///   so it is carried over by `freeze()` but cannot be touched from outside the module.
+///   Skipped fields are also excluded from `from_str` and `into_iter`.
+/// - `#[destructure(skip_if = "path::to::predicate")]`: Generates `visible_fields(&self)` on the Destruct structure,
+///   which lists the names of the fields but leaves out this field while `predicate(&destruct)` returns `true`.
+///   Unlike `skip`, the field stays public and is only hidden at runtime (e.g. an empty optional on serialization).
+/// - `#[destructure(validate = "path::to::fn")]`: With `#[destructure(validators)]` on the structure, generates
+///   `validate_all(&self)` on the Destruct structure, which calls `fn(&field) -> Result<(), String>` for each field
+///   given a validator and collects the results keyed by the field names, e.g. to report every invalid input of a form.
+///   Fields without a validator are left out. It cannot be combined with `group`.
+/// - `#[destructure(redact)]`: Shows the field as `<redacted>` in the `Debug` generated by `debug`,
+///   so that secrets such as passwords are never printed. Combined with `skip` (i.e. `#[destructure(skip, redact)]`),
+///   the skipped field still appears in the output, but only by its name.
+/// - `#[destructure(skip, unsafe_set)]`: Generates `unsafe fn set_<field>(&mut self, value)` on the Destruct structure,
+///   an explicit escape hatch overriding the skipped field from outside the module.
+///   It is memory-safe, and `unsafe` only marks the bypassed encapsulation.
+/// - `#[destructure(try_from = "String")]`: Exposes the field as `String` in the Destruct structure.
+///   `into_destruct()` converts the field by `Into`, and it is validated back by `TryFrom` when restoring.
+///   Since restoring may fail, `freeze()` is replaced by `try_freeze()`, `reconstruct()` is not generated,
+///   and the error `E` of `try_reconstruct()` must be convertible from the error of `try_freeze()`.
+/// - `#[destructure(sort_key)]`: Implements `PartialEq`, `Eq`, `PartialOrd` and `Ord` for the Destruct structure comparing
+///   only the marked field, e.g. to sort by `created_at`. So that the ordering agrees with the equality,
+///   it cannot be combined with `eq_skip` or `id`. Only one field can be marked, and it cannot be combined with `group`.
+/// - `#[destructure(deep_clone)]`: With `#[destructure(clone)]` on the structure, clones the value behind an `Arc<T>`
+///   or `Rc<T>` field into a new pointer in the generated `Clone`, instead of bumping the reference count,
+///   so that the clone does not share it. `T` must implement `Clone`.
+/// - `#[destructure(eq_skip)]`: Implements `PartialEq` for the Destruct structure comparing all fields
+///   except those marked (e.g. timestamps). Unlike `skip`, it only affects the comparison.
+/// - `#[destructure(id)]`: Implements `PartialEq`, `Eq` and `Hash` for the Destruct structure comparing and hashing
+///   only the marked fields, so that entities are equal when their identities are. Several fields may be marked
+///   for a composite identity. It cannot be combined with `eq_skip` or `group`.
+/// - `#[destructure(skip_drop, init = "Vec::new()")]`: Leaves the field out of the Destruct structure entirely.
+///   The field is dropped by `into_destruct()`, and initialized again by the `init` expression on `freeze()`,
+///   which suits caches or handles that are not worth carrying through the update.
+/// - `#[destructure(order = 1)]`: Places the field at the given position in the generated structure,
+///   e.g. for serialization formats which depend on the order of fields. Fields with `order` come first
+///   in ascending order, followed by the others in declaration order. Each order must be unique.
+/// - `#[destructure(zeroize)]`: Wraps the field of the Destruct structure in `zeroize::Zeroizing`, so the decomposed
+///   secret is wiped when the Destruct structure is dropped. `freeze()` takes the value out by `core::mem::take`,
+///   so the field type must implement `Zeroize` and `Default` (a missing `Default` is reported at the field).
+///   The other options see the wrapped type.
+///   Requires the `zeroize` feature, and `zeroize` as a dependency of the user crate.
+/// - `#[destructure(collection)]`: Generates `push_<field>(value)` for a `Vec` field, or `insert_<field>(...)`
+///   for a `HashSet` or `HashMap` field, on the Destruct structure delegating to the collection.
+///   The collection is detected by the last segment of the type path.
+/// - `#[destructure(generic_field)]`: Marks the field whose type is a type parameter `T` of the structure,
+///   generating `map_<field>(self, f: impl FnOnce(T) -> U)` on the original structure, which rebuilds it as `Name<U>`.
+///   Only one field can be marked, and no other field may refer to `T`.
+/// - `#[destructure(cow_accessor)]`: Generates `<field>_cow(&self)` on the original structure,
+///   which borrows the field as `Cow<'_, Type>` so that heavy fields are only cloned on write.
+///   The field type must implement `Clone`.
+/// - `#[destructure(group = "meta")]`: Gathers the fields marked with the same name into a sub-structure
+///   (e.g. `DestructBookMeta`) held by the Destruct structure as `meta`, and flattens them back on `freeze()`.
+///   Grouped fields are not covered by the other options of the Destruct structure, such as `into_iter` or `debug`.
+///   Not supported on generic structures. With `json`, the sub-structure derives `serde` as well and is serialized
+///   as a nested object, unless a field of the group is given `#[destructure(group = "meta", serde)]`,
+///   which flattens it by `#[serde(flatten)]` to keep the JSON flat.
+///
+/// `#[deprecated]` on a field is forwarded to the field of the Destruct structure,
+/// so accessing it through the Destruct structure warns as well.
+///
+/// `#[cfg(...)]` on a field is forwarded to the field of the Destruct structure, `into_destruct()` and `freeze()`,
+/// so that feature-dependent fields stay in sync. The other options do not take it into account.
+//noinspection DuplicatedCode
+#[proc_macro_derive(Destructure, attributes(destructure))]
+pub fn derive_destructure(input: TokenStream) -> TokenStream {
+    let mut ast = parse_macro_input!(input as DeriveInput);
+    replace_self(&mut ast);
+    let name = &ast.ident;
+    let generics = &ast.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let container = match ContainerAttributes::parse(&ast.attrs) {
+        Ok(container) => container,
+        Err(e) => return e.to_compile_error().into()
+    };
+
+    if container.transparent {
+        if let Some(option) = container.unused(&["transparent", "methods"]) {
+            return syn::Error::new(option.span(), format!("`{}` cannot be combined with `transparent`.", option)).to_compile_error().into()
+        }
+        return derive_transparent(&ast, &container)
+    }
+    // `Destruct{Name}Ref` is only generated by `borrow_view`, so its options are not used without it.
+    if let Some(option) = container.given.iter().find(|option| !container.borrow_view && (*option == "lifetime" || *option == "merge_from_ref")) {
+        return syn::Error::new(option.span(), format!("`{}` requires `borrow_view`, since it only applies to `Destruct{{Name}}Ref`.", option)).to_compile_error().into()
+    }
+
+    let generate = format!("Destruct{}", name);
+    let generate_ident = Ident::new(&generate, name.span());
+
+    let fields = if let Data::Struct(DataStruct { fields: Fields::Named(FieldsNamed { ref named, ..}), .. }) = ast.data {
+        named
+    } else {
+        return quote_spanned! { name.span() => compile_error!("Only structures with named fields are supported."); }.into()
+    };
+
+    let (members, dropped): (Vec<_>, Vec<_>) = match parse_fields(&container, fields) {
+        Ok(members) => members.into_iter().partition(|(_, attrs)| !attrs.skip_drop),
+        Err(e) => return e.to_compile_error().into()
+    };
+    if let Some((field, _)) = dropped.iter().find(|(_, attrs)| attrs.group.is_some()) {
+        return syn::Error::new(field.span(), "`skip_drop` cannot be combined with `group`.").to_compile_error().into()
+    }
+    if members.iter().any(|(_, attrs)| attrs.zeroize) && cfg!(not(feature = "zeroize")) {
+        return quote_spanned! { name.span() => compile_error!("`zeroize` requires the `zeroize` feature of `destructure` to be enabled."); }.into()
+    }
+    let groups = match collect_groups(&ast, &generate, &members) {
+        Ok(groups) => groups,
+        Err(e) => return e.to_compile_error().into()
+    };
+    let declared = fields;
+    // Options of the Destruct structure only cover the fields placed on it directly.
+    let fields = members.iter().filter(|(_, attrs)| attrs.group.is_none()).collect::<Vec<_>>();
+    let exposed = fields.iter().filter(|(_, attrs)| !attrs.skip).copied().collect::<Vec<_>>();
+
+    if container.private_fields && container.field_visibility.is_some() {
+        return quote_spanned! { name.span() => compile_error!("`field_visibility` cannot be combined with `pub_fields = false`."); }.into()
+    }
+    let field_vis = match &container.field_visibility {
+        Some(vis) => quote! { #vis },
+        None => quote! { pub },
+    };
+
+    let destruct_field = |(field, attrs): &(&Field, FieldAttributes)| {
+        let name = destruct_ident(field, attrs);
+        let ty = destruct_type(field, attrs);
+        let vis = (!attrs.skip && !container.private_fields).then(|| field_vis.clone());
+        // Skipped fields are not part of the public surface, so they are left out of the serialized form as well.
+        let serde_skip = (attrs.skip && container.json).then(|| quote! { #[serde(skip)] });
+        let forwarded = field.attrs.iter().filter(|attr| attr.path().is_ident("deprecated") || attr.path().is_ident("cfg"));
+        quote! {
+            #(#forwarded)*
+            #serde_skip
+            #vis #name: #ty
+        }
+    };
+
+    // `cloned` expands the fields from `&self` by `Clone` instead of moving them out of `self`.
+    let expand_field = |(field, attrs): &(&Field, FieldAttributes), cloned: bool| {
+        let name = &field.ident;
+        let dest = destruct_ident(field, attrs);
+        let source = match cloned {
+            true => quote! { ::core::clone::Clone::clone(&self.#name) },
+            false => quote! { self.#name }
+        };
+        let source = match &attrs.try_from {
+            Some(_) => quote! { ::core::convert::Into::into(#source) },
+            None => source
+        };
+        let source = match attrs.zeroize {
+            true => quote! { ::zeroize::Zeroizing::new(#source) },
+            false => source
+        };
+        let cfgs = cfg_attrs(field);
+        quote! {
+            #(#cfgs)* #dest: #source
+        }
+    };
+
+    let mut destruction = members.iter().filter_map(|member| match &member.1.group {
+        None => Some(destruct_field(member)),
+        Some(group) => groups.iter()
+            .find(|g| g.ident == *group && std::ptr::eq(g.members[0], member))
+            .map(|g| {
+                let ident = &g.ident;
+                let struct_ident = &g.struct_ident;
+                let flatten = g.flatten.then(|| quote! { #[serde(flatten)] });
+                quote! {
+                    #flatten
+                    #field_vis #ident: #struct_ident
+                }
+            })
+    }).collect::<Vec<_>>();
+
+    let expand = |cloned: bool| members.iter().filter_map(|member| match &member.1.group {
+        None => Some(expand_field(member, cloned)),
+        Some(group) => groups.iter()
+            .find(|g| g.ident == *group && std::ptr::eq(g.members[0], member))
+            .map(|g| {
+                let ident = &g.ident;
+                let struct_ident = &g.struct_ident;
+                let grouped = g.members.iter().map(|member| expand_field(member, cloned));
+                quote! {
+                    #ident: #struct_ident { #(#grouped,)* }
+                }
+            })
+    }).collect::<Vec<_>>();
+    let mut expanded = expand(false);
+
+    if container.json && cfg!(not(feature = "serde_json")) {
+        return quote_spanned! { name.span() => compile_error!("`json` requires the `serde_json` feature of `destructure` to be enabled."); }.into()
+    }
+    if let Some(g) = groups.iter().find(|g| g.flatten && !container.json) {
+        return syn::Error::new(g.ident.span(), "`serde` requires `json`, which derives `serde` for the Destruct structure and its groups.").to_compile_error().into()
+    }
+    let serde_derive = container.json.then(|| quote! {
+        #[derive(::serde::Serialize, ::serde::Deserialize)]
+    });
+
+    // Private fields which are not taken from the original structure, initialized wherever the Destruct structure is built.
+    let mut hidden_inits = Vec::new();
+    let serde_skip = container.json.then(|| quote! { #[serde(skip)] });
+    // Generic parameters whose fields are all dropped or converted by `try_from` would be unused.
+    let phantom = phantom_type(generics, members.iter().map(|(field, attrs)| destruct_type(field, attrs)));
+    if let Some(phantom) = &phantom {
+        destruction.push(quote! {
+            #serde_skip
+            __destruct_phantom: #phantom
+        });
+        hidden_inits.push(quote! {
+            __destruct_phantom: ::core::marker::PhantomData
+        });
+    }
+    // A private field prevents the Destruct structure from being built by a struct literal outside the module.
+    if container.sealed {
+        destruction.push(quote! {
+            #serde_skip
+            __destruct_seal: ()
+        });
+        hidden_inits.push(quote! {
+            __destruct_seal: ()
+        });
+    }
+    expanded.extend(hidden_inits.iter().cloned());
+
+    let to_destruct = match container.by_ref_clone {
+        false => None,
+        true if is_packed(&ast.attrs) => return quote_spanned! { name.span() => compile_error!("`by_ref_clone` cannot be used on `#[repr(packed)]` structures, since references to their fields may be misaligned."); }.into(),
+        true => {
+            let bounded = with_predicates(&container, generics, members.iter().map(|(field, _)| {
+                let ty = &field.ty;
+                parse_quote! { #ty: ::core::clone::Clone }
+            }));
+            let (_, _, clone_where_clause) = bounded.split_for_impl();
+            let mut cloned = expand(true);
+            cloned.extend(hidden_inits.iter().cloned());
+            Some(quote! {
+                /// Convert the field value to a fully disclosed Destruct structure by cloning each field,
+                /// leaving the original structure as is.
+                pub fn to_destruct(&self) -> #generate_ident #ty_generics
+                    #clone_where_clause
+                {
+                    #generate_ident { #(#cloned,)* }
+                }
+            })
+        }
+    };
+
+    let doc_hidden = container.doc_hidden.then(|| quote! { #[doc(hidden)] });
+    let group_structs = groups.iter().map(|g| {
+        let struct_ident = &g.struct_ident;
+        let grouped = g.members.iter().map(|member| destruct_field(member));
+        quote! {
+            /// Do not have an explicit implementation for this structure.
+            #doc_hidden
+            #serde_derive
+            pub struct #struct_ident {
+                #(#grouped,)*
+            }
+        }
+    });
+
+    let freeze_source = |(field, attrs): &(&Field, FieldAttributes)| {
+        let dest = destruct_ident(field, attrs);
+        let source = match &attrs.group {
+            Some(group) => quote! { self.#group.#dest },
+            None => quote! { self.#dest }
+        };
+        // `Zeroizing` wipes the value on drop, so it is taken out leaving the default to be wiped.
+        // The take is spanned to the field, so that a type without `Default` is reported there.
+        match attrs.zeroize {
+            true => {
+                let inner = attrs.try_from.as_ref().unwrap_or(&field.ty);
+                let take = quote_spanned! { inner.span() => ::core::mem::take::<#inner> };
+                quote! { { let mut secret = #source; #take(&mut *secret) } }
+            },
+            false => source
+        }
+    };
+    // Local holding the converted value of a `try_from` field in `try_freeze_all()`.
+    let built_local = |field: &Field, attrs: &FieldAttributes| Ident::new(&format!("__built_{}", destruct_ident(field, attrs).unraw()), field.span());
+    // With `built`, the `try_from` fields are taken from the locals converted beforehand by `try_freeze_all()`.
+    let freeze_values = |built: bool| members.iter().map(|member| {
+        let (field, attrs) = member;
+        let ty = &field.ty;
+        let source = freeze_source(member);
+        let value = match (&attrs.try_from, built) {
+            (Some(_), true) => built_local(field, attrs).into_token_stream(),
+            (Some(from), false) => quote! { <#ty as ::core::convert::TryFrom<#from>>::try_from(#source)? },
+            (None, _) => source
+        };
+        (*field, value)
+    }).chain(dropped.iter().map(|(field, attrs)| {
+        let init = &attrs.init;
+        (*field, quote! { #init })
+    })).collect::<Vec<_>>();
+    if container.freeze_via.is_some() && members.iter().chain(&dropped).any(|(field, _)| cfg_attrs(field).next().is_some()) {
+        return quote_spanned! { name.span() => compile_error!("`freeze_via` cannot be combined with `#[cfg]` on fields, since the arguments cannot be conditional."); }.into()
+    }
+    let rebuild_with = |mut freeze: Vec<(&Field, _)>| match &container.freeze_via {
+        Some(constructor) => {
+            freeze.sort_by_key(|(field, _)| declared.iter().position(|declared| std::ptr::eq(declared, *field)));
+            let values = freeze.iter().map(|(_, value)| value);
+            quote! { #constructor(#(#values),*) }
+        },
+        None => {
+            let inits = freeze.iter().map(|(field, value)| {
+                let name = &field.ident;
+                let cfgs = cfg_attrs(field);
+                quote! { #(#cfgs)* #name: #value }
+            });
+            quote! { #name { #(#inits,)* } }
+        }
+    };
+    let rebuild = rebuild_with(freeze_values(false));
+
+    // With `try_from` fields, the Destruct structure can only be restored by `try_freeze()`.
+    let fallible = members.iter().filter(|(_, attrs)| attrs.try_from.is_some()).collect::<Vec<_>>();
+    let freeze_error = match (&container.error, fallible.as_slice()) {
+        (Some(error), _) => Some(quote! { #error }),
+        (None, []) => None,
+        (None, [(field, attrs)]) => {
+            let ty = &field.ty;
+            let from = &attrs.try_from;
+            Some(quote! { <#ty as ::core::convert::TryFrom<#from>>::Error })
+        },
+        (None, _) => return quote_spanned! { name.span() => compile_error!("`error` is required when several fields use `try_from`."); }.into()
+    };
+
+    let asserts = &container.asserts;
+    let build_error_ident = Ident::new(&format!("{}BuildError", name), name.span());
+    let build_failure_ident = Ident::new(&format!("{}BuildFailure", name), name.span());
+    let (try_freeze_all, build_error) = match container.build_error {
+        true if !generics.params.is_empty() => return quote_spanned! { name.span() => compile_error!("`build_error` is not supported on generic structures."); }.into(),
+        true if fallible.is_empty() => return quote_spanned! { name.span() => compile_error!("`build_error` requires fields with `try_from`."); }.into(),
+        true if container.from => return quote_spanned! { name.span() => compile_error!("`build_error` cannot be combined with `from`, which implements `TryFrom` by `try_freeze()`."); }.into(),
+        true => {
+            let variants = fallible.iter().map(|(field, attrs)| Ident::new(&to_pascal_case(&destruct_key(field, attrs)), field.span())).collect::<Vec<_>>();
+            let errors = fallible.iter().map(|(field, attrs)| {
+                let ty = &field.ty;
+                let from = &attrs.try_from;
+                quote! { <#ty as ::core::convert::TryFrom<#from>>::Error }
+            });
+            let conversions = fallible.iter().map(|member| {
+                let (field, attrs) = member;
+                let ty = &field.ty;
+                let from = &attrs.try_from;
+                let source = freeze_source(member);
+                quote! { <#ty as ::core::convert::TryFrom<#from>>::try_from(#source) }
+            });
+            let locals = fallible.iter().map(|(field, attrs)| built_local(field, attrs)).collect::<Vec<_>>();
+            let rebuild = rebuild_with(freeze_values(true));
+            let failure_doc = format!("Conversion of a `try_from` field which failed while building [`{}`].", name);
+            let error_doc = format!("Every conversion which failed while building [`{}`] from [`{}`].", name, generate);
+            (
+                Some(quote! {
+                    /// Works the same as `try_freeze()`, but attempts the conversions of every `try_from` field
+                    /// and collects all the errors instead of returning the first one.
+                    #[track_caller]
+                    pub fn try_freeze_all(self) -> Result<#name, #build_error_ident> {
+                        #(::core::debug_assert!(#asserts);)*
+                        match (#(#conversions,)*) {
+                            (#(::core::result::Result::Ok(#locals),)*) => Ok(#rebuild),
+                            (#(#locals,)*) => {
+                                let mut failures = Vec::new();
+                                #(
+                                    if let ::core::result::Result::Err(e) = #locals {
+                                        failures.push(#build_failure_ident::#variants(e));
+                                    }
+                                )*
+                                Err(#build_error_ident { failures })
+                            }
+                        }
+                    }
+                }),
+                Some(quote! {
+                    #[doc = #failure_doc]
+                    #doc_hidden
+                    #[derive(Debug)]
+                    pub enum #build_failure_ident {
+                        #(#variants(#errors),)*
+                    }
+
+                    #[doc = #error_doc]
+                    #doc_hidden
+                    #[derive(Debug)]
+                    pub struct #build_error_ident {
+                        /// The failed conversions, in field order.
+                        pub failures: Vec<#build_failure_ident>,
+                    }
+
+                    #[automatically_derived]
+                    impl ::core::convert::TryFrom<#generate_ident> for #name {
+                        type Error = #build_error_ident;
+
+                        fn try_from(value: #generate_ident) -> Result<Self, Self::Error> {
+                            value.try_freeze_all()
+                        }
+                    }
+                })
+            )
+        },
+        false => (None, None)
+    };
+
+    let freeze = match &freeze_error {
+        Some(error) => quote! {
+            /// Restore the Destruct structure to its original structure again,
+            /// converting back the fields given `try_from`.
+            #[inline]
+            #[track_caller]
+            pub fn try_freeze(self) -> Result<#name #ty_generics, #error> {
+                #(::core::debug_assert!(#asserts);)*
+                Ok(#rebuild)
+            }
+        },
+        None => quote! {
+            /// Restore the Destruct structure to its original structure again.
+            #[inline]
+            #[track_caller]
+            pub fn freeze(self) -> #name #ty_generics {
+                #(::core::debug_assert!(#asserts);)*
+                #rebuild
+            }
+        }
+    };
+
+    let reconstruct = (container.generates("reconstruct") && freeze_error.is_none()).then(|| quote! {
+        /// It provides a mechanism for replacing the contents by [`into_destruct()`]
+        /// and changing the actual value by [`freeze()`] using a limited closure.
+        ///
+        /// If you wish to use Result, see `try_reconstruct()`.
+        #[track_caller]
+        pub fn reconstruct(self, f: impl FnOnce(&mut #generate_ident #ty_generics)) -> Self {
+            let mut dest = self.into_destruct();
+            f(&mut dest);
+            dest.freeze()
+        }
+
+        /// Same as [`reconstruct()`], but passes `ctx` to the closure as an explicit argument,
+        /// so that the update function can be reused with different contexts (e.g. a clock).
+        #[track_caller]
+        pub fn reconstruct_with_ctx<__C>(self, ctx: __C, f: impl FnOnce(&mut #generate_ident #ty_generics, __C)) -> Self {
+            let mut dest = self.into_destruct();
+            f(&mut dest, ctx);
+            dest.freeze()
+        }
+    });
+
+    // Free functions go to `{name}_helpers`, so that several structures in the same module do not collide.
+    let helpers_ident = Ident::new(&format!("{}_helpers", to_snake_case(&name.to_string())), name.span());
+    let helpers_doc = format!("Free helper functions for [`{}`].", name);
+    let vis = &ast.vis;
+    let helpers = match (container.helpers, &reconstruct) {
+        (false, _) => None,
+        (true, None) => return quote_spanned! { name.span() => compile_error!("`helpers` requires `reconstruct`, which is not generated with `try_from` fields or when left out of `methods`."); }.into(),
+        (true, Some(_)) => Some(quote! {
+            #[doc = #helpers_doc]
+            #doc_hidden
+            #vis mod #helpers_ident {
+                #[allow(unused_imports)]
+                use super::*;
+
+                /// Reconstructs the value held by `opt` in place, doing nothing if it is `None`.
+                ///
+                /// It saves the `take()` and put back around `reconstruct()` for optional fields of other structures.
+                pub fn reconstruct_option #impl_generics (
+                    opt: &mut ::core::option::Option<#name #ty_generics>,
+                    f: impl FnOnce(&mut #generate_ident #ty_generics)
+                ) #where_clause {
+                    if let ::core::option::Option::Some(value) = opt.take() {
+                        *opt = ::core::option::Option::Some(value.reconstruct(f));
+                    }
+                }
+            }
+        })
+    };
+
+    let try_reconstruct = container.generates("try_reconstruct").then(|| match &freeze_error {
+        Some(error) => quote! {
+            /// Since some fields are given `try_from`, the error of `try_freeze()` is converted into `E`.
+            #[track_caller]
+            pub fn try_reconstruct<E>(self, f: impl FnOnce(&mut #generate_ident #ty_generics) -> Result<(), E>) -> Result<Self, E>
+                where E: ::core::convert::From<#error>
+            {
+                let mut dest = self.into_destruct();
+                f(&mut dest)?;
+                Ok(dest.try_freeze()?)
+            }
+        },
+        None => quote! {
+            #[track_caller]
+            pub fn try_reconstruct<E>(self, f: impl FnOnce(&mut #generate_ident #ty_generics) -> Result<(), E>) -> Result<Self, E> {
+                let mut dest = self.into_destruct();
+                f(&mut dest)?;
+                Ok(dest.freeze())
+            }
+        }
+    });
+
+    // `Destruct{Name}ReconstructError` tells which structure failed when reconstructions are nested.
+    let reconstruct_error_ident = Ident::new(&format!("{}ReconstructError", generate), name.span());
+    let structure = name.to_string();
+    let try_reconstruct_ctx = container.generates("try_reconstruct").then(|| {
+        let (bound, freeze) = match &freeze_error {
+            Some(error) => (
+                Some(quote! { where E: ::core::convert::From<#error> }),
+                quote! { dest.try_freeze().map_err(|e| wrap(::core::convert::From::from(e))) }
+            ),
+            None => (None, quote! { Ok(dest.freeze()) })
+        };
+        let ctx_doc = format!("Works the same as `try_reconstruct()`, but wraps the error into [`{}`] carrying the name of the structure.", reconstruct_error_ident);
+        quote! {
+            #[doc = #ctx_doc]
+            #[track_caller]
+            pub fn try_reconstruct_ctx<E>(self, f: impl FnOnce(&mut #generate_ident #ty_generics) -> Result<(), E>) -> Result<Self, #reconstruct_error_ident<E>>
+                #bound
+            {
+                let wrap = |source| #reconstruct_error_ident { structure: #structure, source };
+                let mut dest = self.into_destruct();
+                f(&mut dest).map_err(wrap)?;
+                #freeze
+            }
+        }
+    });
+    let reconstruct_error = container.generates("try_reconstruct").then(|| {
+        let error_doc = format!("Error returned by `try_reconstruct_ctx()` of [`{}`], wrapping the error of the closure.", name);
+        quote! {
+            #[doc = #error_doc]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #doc_hidden
+            pub struct #reconstruct_error_ident<E> {
+                /// Name of the structure which failed to be reconstructed.
+                pub structure: &'static str,
+                /// The underlying error.
+                pub source: E,
+            }
+
+            #[automatically_derived]
+            impl<E: ::core::fmt::Display> ::core::fmt::Display for #reconstruct_error_ident<E> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    write!(f, "failed to reconstruct `{}`: {}", self.structure, self.source)
+                }
+            }
+
+            #[automatically_derived]
+            impl<E: ::std::error::Error + 'static> ::std::error::Error for #reconstruct_error_ident<E> {
+                fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+                    Some(&self.source)
+                }
+            }
+        }
+    });
+
+    let try_reconstruct_all = container.generates("try_reconstruct_all").then(|| match &freeze_error {
+        Some(error) => quote! {
+            /// Works the same as `try_reconstruct()`, but `f` pushes every error it finds into the given `Vec`
+            /// instead of returning the first one. If any error is pushed, or `try_freeze()` fails, they are returned.
+            #[track_caller]
+            pub fn try_reconstruct_all<E>(self, f: impl FnOnce(&mut #generate_ident #ty_generics, &mut Vec<E>)) -> Result<Self, Vec<E>>
+                where E: ::core::convert::From<#error>
+            {
+                let mut dest = self.into_destruct();
+                let mut errors = Vec::new();
+                f(&mut dest, &mut errors);
+                if !errors.is_empty() {
+                    return Err(errors)
+                }
+                dest.try_freeze().map_err(|e| vec![::core::convert::From::from(e)])
+            }
+        },
+        None => quote! {
+            /// Works the same as `try_reconstruct()`, but `f` pushes every error it finds into the given `Vec`
+            /// instead of returning the first one. If any error is pushed, they are returned.
+            #[track_caller]
+            pub fn try_reconstruct_all<E>(self, f: impl FnOnce(&mut #generate_ident #ty_generics, &mut Vec<E>)) -> Result<Self, Vec<E>> {
+                let mut dest = self.into_destruct();
+                let mut errors = Vec::new();
+                f(&mut dest, &mut errors);
+                if !errors.is_empty() {
+                    return Err(errors)
+                }
+                Ok(dest.freeze())
+            }
+        }
+    });
+
+    let replace_with = if container.replace_with {
+        if freeze_error.is_some() {
+            return quote_spanned! { name.span() => compile_error!("`replace_with` cannot be used with `try_from` fields."); }.into()
+        }
+        let bounded = with_predicates(&container, generics, [parse_quote! { #name #ty_generics: ::core::default::Default }]);
+        let (_, _, replace_where_clause) = bounded.split_for_impl();
+        Some(quote! {
+            /// Replaces the value behind `&mut self` by the Destruct structure returned from `f`, which is given the current fields.
+            ///
+            /// The value is taken out by [`std::mem::take`], so if `f` panics, `Default` is left in its place.
+            pub fn replace_with(&mut self, f: impl FnOnce(#generate_ident #ty_generics) -> #generate_ident #ty_generics)
+                #replace_where_clause
+            {
+                let dest = ::core::mem::take(self).into_destruct();
+                *self = f(dest).freeze();
+            }
+        })
+    } else {
+        None
+    };
+
+    if container.swappable && is_packed(&ast.attrs) {
+        return quote_spanned! { name.span() => compile_error!("`swappable` cannot be used on `#[repr(packed)]` structures, since references to their fields may be misaligned."); }.into()
+    }
+    let swaps = members.iter().filter(|(_, attrs)| container.swappable && !attrs.skip).map(|(field, _)| {
+        let ident = field.ident.as_ref().unwrap();
+        let cfgs = cfg_attrs(field);
+        let swap = Ident::new(&format!("swap_{}_with", ident.unraw()), ident.span());
+        quote! {
+            /// Exchanges the field with the one of `other`, leaving the other fields of both untouched.
+            #(#cfgs)*
+            pub fn #swap(&mut self, other: &mut Self) {
+                ::core::mem::swap(&mut self.#ident, &mut other.#ident);
+            }
+        }
+    });
+
+    let test_fixture = if container.test_fixture {
+        if freeze_error.is_some() {
+            return quote_spanned! { name.span() => compile_error!("`test_fixture` cannot be used with `try_from` fields."); }.into()
+        }
+        let bounded = with_predicates(&container, generics, members.iter().map(|(field, _)| {
+            let ty = &field.ty;
+            parse_quote! { #ty: ::core::default::Default }
+        }));
+        let (fixture_impl_generics, _, fixture_where_clause) = bounded.split_for_impl();
+        // Dropped fields are filled by their `init`, the same as in `freeze()`.
+        let defaults = members.iter().map(|(field, _)| {
+            let ident = &field.ident;
+            let cfgs = cfg_attrs(field);
+            quote! { #(#cfgs)* #ident: ::core::default::Default::default() }
+        }).chain(dropped.iter().map(|(field, attrs)| {
+            let ident = &field.ident;
+            let init = &attrs.init;
+            let cfgs = cfg_attrs(field);
+            quote! { #(#cfgs)* #ident: #init }
+        }));
+        Some(quote! {
+            #[cfg(test)]
+            #[automatically_derived]
+            impl #fixture_impl_generics #name #ty_generics #fixture_where_clause {
+                /// Builds an instance for tests with every field set to its `Default`.
+                pub fn fixture() -> Self {
+                    Self { #(#defaults,)* }
+                }
+
+                /// Builds an instance for tests from [`fixture()`](Self::fixture), overriding the fields in `f`.
+                pub fn fixture_with(f: impl FnOnce(&mut #generate_ident #ty_generics)) -> Self {
+                    let mut dest = Self::fixture().into_destruct();
+                    f(&mut dest);
+                    dest.freeze()
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let (editor, editor_struct) = match container.editor {
+        true => {
+            let editor_ident = Ident::new(&format!("{}Editor", name), name.span());
+            let lifetime = fresh_lifetime(generics, "'__origin_editor_lifetime");
+            let generics_with_lt = with_lifetime(generics, &lifetime);
+            let (editor_impl_generics, editor_ty_generics, _) = generics_with_lt.split_for_impl();
+            let generics_with_anonymous = with_lifetime(generics, &Lifetime::new("'_", generics.span()));
+            let (_, ty_generics_with_anonymous, _) = generics_with_anonymous.split_for_impl();
+            let edited = members.iter().filter(|(_, attrs)| !attrs.skip).collect::<Vec<_>>();
+            let pending = edited.iter().map(|(field, _)| {
+                let ident = &field.ident;
+                let ty = &field.ty;
+                let cfgs = cfg_attrs(field);
+                quote! { #(#cfgs)* #ident: ::core::option::Option<#ty> }
+            });
+            let nones = edited.iter().map(|(field, _)| {
+                let ident = &field.ident;
+                let cfgs = cfg_attrs(field);
+                quote! { #(#cfgs)* #ident: ::core::option::Option::None }
+            });
+            let setters = edited.iter().map(|(field, attrs)| {
+                let ident = &field.ident;
+                let ty = &field.ty;
+                let cfgs = cfg_attrs(field);
+                let setter = Ident::new(&format!("set_{}", destruct_key(field, attrs)), destruct_ident(field, attrs).span());
+                quote! {
+                    /// Stages a new value of the field, which is written back by `commit()`.
+                    #(#cfgs)*
+                    pub fn #setter(&mut self, value: #ty) -> &mut Self {
+                        self.#ident = ::core::option::Option::Some(value);
+                        self
+                    }
+                }
+            });
+            let writes = edited.iter().map(|(field, _)| {
+                let ident = &field.ident;
+                let cfgs = cfg_attrs(field);
+                quote! {
+                    #(#cfgs)*
+                    if let ::core::option::Option::Some(value) = self.#ident.take() {
+                        self.target.#ident = value;
+                    }
+                }
+            });
+            let editor_doc = format!("Editor borrowing [`{}`] mutably, whose staged changes are written back by `commit()`.", name);
+            (
+                Some(quote! {
+                    /// Borrows the value as an editor, on which fields can be set over time and written back at once by `commit()`.
+                    pub fn edit(&mut self) -> #editor_ident #ty_generics_with_anonymous {
+                        #editor_ident { target: self, #(#nones,)* }
+                    }
+                }),
+                Some(quote! {
+                    #[doc = #editor_doc]
+                    #[must_use = "the staged changes are only written back by `commit()`"]
+                    #doc_hidden
+                    pub struct #editor_ident #generics_with_lt #where_clause {
+                        target: &#lifetime mut #name #ty_generics,
+                        #(#pending,)*
+                    }
+
+                    #[automatically_derived]
+                    impl #editor_impl_generics #editor_ident #editor_ty_generics #where_clause {
+                        #(#setters)*
+
+                        /// Writes the staged changes back into the borrowed value, leaving the other fields as they are.
+                        ///
+                        /// The staged changes are cleared, so it can end a chain of setters (e.g. `book.edit().set_name(name).commit()`).
+                        pub fn commit(&mut self) {
+                            #(#writes)*
+                        }
+                    }
+                })
+            )
+        },
+        false => (None, None)
+    };
+
+    let reconstruct_tracked = if container.tracked {
+        if freeze_error.is_some() {
+            return quote_spanned! { name.span() => compile_error!("`tracked` cannot be used with `try_from` fields."); }.into()
+        }
+        let bounded = with_predicates(&container, generics, fields.iter().map(|(field, attrs)| {
+            let ty = destruct_type(field, attrs);
+            parse_quote! { #ty: ::core::clone::Clone + ::core::cmp::PartialEq }
+        }));
+        let (_, _, tracked_where_clause) = bounded.split_for_impl();
+        let dests = fields.iter()
+            .map(|(field, attrs)| destruct_ident(field, attrs))
+            .collect::<Vec<_>>();
+        let keys = fields.iter().map(|(field, attrs)| destruct_key(field, attrs));
+        let snapshots = dests.iter().map(|dest| Ident::new(&format!("__before_{}", dest.unraw()), dest.span())).collect::<Vec<_>>();
+        Some(quote! {
+            /// Works the same as [`reconstruct()`], but records the names of the fields changed by `f` into `sink`.
+            ///
+            /// Fields are only compared in builds with `debug_assertions`, otherwise `sink` is left untouched.
+            #[track_caller]
+            pub fn reconstruct_tracked(self, f: impl FnOnce(&mut #generate_ident #ty_generics), sink: &mut Vec<&'static str>) -> Self
+                #tracked_where_clause
+            {
+                let mut dest = self.into_destruct();
+                #[cfg(debug_assertions)]
+                let (#(#snapshots,)*) = (#(::core::clone::Clone::clone(&dest.#dests),)*);
+                f(&mut dest);
+                #[cfg(debug_assertions)]
+                {
+                    #(
+                        if #snapshots != dest.#dests {
+                            sink.push(#keys);
+                        }
+                    )*
+                }
+                #[cfg(not(debug_assertions))]
+                let _ = sink;
+                dest.freeze()
+            }
+        })
+    } else {
+        None
+    };
+
+    let json = container.json.then(|| quote! {
+        #[automatically_derived]
+        impl #impl_generics #generate_ident #ty_generics #where_clause {
+            /// Deserializes the Destruct structure from a `serde_json::Value` without going through a string.
+            pub fn from_json_value(value: ::serde_json::Value) -> ::core::result::Result<Self, ::serde_json::Error>
+                where Self: ::serde::de::DeserializeOwned
+            {
+                ::serde_json::from_value(value)
+            }
+
+            /// Serializes the Destruct structure into a `serde_json::Value` without going through a string.
+            ///
+            /// # Panics
+            /// Panics if a field fails to serialize, e.g. a map whose keys are not strings.
+            pub fn to_json_value(&self) -> ::serde_json::Value
+                where Self: ::serde::Serialize
+            {
+                ::serde_json::to_value(self).expect("the Destruct structure should serialize into a `serde_json::Value`")
+            }
+        }
+    });
+
+    let cow_fields = members.iter().chain(&dropped).filter(|(_, attrs)| attrs.cow_accessor).collect::<Vec<_>>();
+    if !cow_fields.is_empty() && is_packed(&ast.attrs) {
+        return quote_spanned! { name.span() => compile_error!("`cow_accessor` cannot be used on `#[repr(packed)]` structures, since references to their fields may be misaligned."); }.into()
+    }
+    let cow_accessors = cow_fields.iter().map(|(field, attrs)| {
+        let name = &field.ident;
+        let ty = &field.ty;
+        let dest = destruct_ident(field, attrs);
+        let accessor = Ident::new(&format!("{}_cow", dest.unraw()), dest.span());
+        quote! {
+            /// Borrows the field as a `Cow`, which is only cloned when it is about to be modified.
+            pub fn #accessor(&self) -> ::std::borrow::Cow<'_, #ty>
+                where #ty: ::core::clone::Clone
+            {
+                ::std::borrow::Cow::Borrowed(&self.#name)
+            }
+        }
+    });
+
+    let mut generic_fields = members.iter().chain(&dropped).filter(|(_, attrs)| attrs.generic_field);
+    let map_generic_field = match (generic_fields.next(), generic_fields.next()) {
+        (Some(_), Some((field, _))) => {
+            return syn::Error::new(field.span(), "`generic_field` can only be given to one field.").to_compile_error().into()
+        },
+        (Some((field, _)), None) => {
+            let param = generics.type_params().find(|param| matches!(&field.ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident(&param.ident)));
+            let Some(param) = param else {
+                return syn::Error::new(field.ty.span(), "`generic_field` requires the field to be of a type parameter of the structure.").to_compile_error().into()
+            };
+            let ident = &param.ident;
+            let others = members.iter().chain(&dropped).filter(|(other, _)| other.ident != field.ident);
+            if let Some((other, _)) = others.clone().find(|(other, _)| mentioned_idents(std::iter::once(&other.ty)).contains(ident)) {
+                return syn::Error::new(other.ty.span(), format!("`generic_field` requires `{}` to be used only by the marked field.", ident)).to_compile_error().into()
+            }
+            let mapped = Ident::new("__U", ident.span());
+            let args = generics.params.iter().map(|param| match param {
+                GenericParam::Lifetime(LifetimeParam { lifetime, .. }) => quote! { #lifetime },
+                GenericParam::Type(TypeParam { ident: param, .. }) if param == ident => quote! { #mapped },
+                GenericParam::Type(TypeParam { ident, .. }) => quote! { #ident },
+                GenericParam::Const(ConstParam { ident, .. }) => quote! { #ident },
+            });
+            let bounds = &param.bounds;
+            let predicates = generics.where_clause.iter()
+                .flat_map(|clause| &clause.predicates)
+                .filter_map(|predicate| match predicate {
+                    WherePredicate::Type(PredicateType { bounded_ty: Type::Path(path), bounds, .. }) if path.qself.is_none() && path.path.is_ident(ident) => {
+                        Some(quote! { #mapped: #bounds })
+                    },
+                    _ => None
+                });
+            let field_name = &field.ident;
+            let method = Ident::new(&format!("map_{}", field_name.as_ref().unwrap().unraw()), field.span());
+            let rest = others.map(|(other, _)| {
+                let name = &other.ident;
+                quote! { #name: self.#name }
+            });
+            Some(quote! {
+                /// Converts the field by `f`, changing the type parameter it carries.
+                pub fn #method<#mapped: #bounds>(self, f: impl FnOnce(#ident) -> #mapped) -> #name<#(#args),*>
+                    where #(#predicates,)*
+                {
+                    #name { #field_name: f(self.#field_name), #(#rest,)* }
+                }
+            })
+        },
+        (None, _) => None
+    };
+
+    let into_destruct_boxed = container.boxed.then(|| quote! {
+        /// Unbox and convert the field value to a fully disclosed Destruct structure.
+        ///
+        /// If you wish to revert the Destruct structure back into a box, see `freeze_boxed()`.
+        pub fn into_destruct_boxed(self: ::std::boxed::Box<Self>) -> #generate_ident #ty_generics {
+            (*self).into_destruct()
+        }
+    });
+
+    let freeze_boxed = container.boxed.then(|| match &freeze_error {
+        Some(error) => quote! {
+            /// Restore the Destruct structure to its original structure in a box again.
+            #[track_caller]
+            pub fn try_freeze_boxed(self) -> Result<::std::boxed::Box<#name #ty_generics>, #error> {
+                self.try_freeze().map(::std::boxed::Box::new)
+            }
+        },
+        None => quote! {
+            /// Restore the Destruct structure to its original structure in a box again.
+            #[track_caller]
+            pub fn freeze_boxed(self) -> ::std::boxed::Box<#name #ty_generics> {
+                ::std::boxed::Box::new(self.freeze())
+            }
+        }
+    });
+
+    if let Some((field, _)) = members.iter().find(|(_, attrs)| attrs.unsafe_set && attrs.group.is_some()) {
+        return syn::Error::new(field.span(), "`unsafe_set` cannot be combined with `group`.").to_compile_error().into()
+    }
+    if container.private_fields && !groups.is_empty() {
+        return quote_spanned! { name.span() => compile_error!("`pub_fields = false` cannot be combined with `group`."); }.into()
+    }
+    let getters = exposed.iter().filter(|_| container.private_fields).map(|(field, attrs)| {
+        let dest = destruct_ident(field, attrs);
+        let ty = destruct_type(field, attrs);
+        quote! {
+            /// Borrows the field, which is kept private by `pub_fields = false`.
+            pub fn #dest(&self) -> &#ty {
+                &self.#dest
+            }
+        }
+    });
+
+    let takes = exposed.iter().filter(|_| container.take).map(|(field, attrs)| {
+        let dest = destruct_ident(field, attrs);
+        let ty = destruct_type(field, attrs);
+        let take = Ident::new(&format!("take_{}", destruct_key(field, attrs)), dest.span());
+        quote! {
+            /// Moves the field out, leaving its `Default` in place.
+            pub fn #take(&mut self) -> #ty
+                where #ty: ::core::default::Default
+            {
+                ::core::mem::take(&mut self.#dest)
+            }
+        }
+    });
+
+    if let Some((field, _)) = members.iter().find(|(_, attrs)| attrs.collection && attrs.group.is_some()) {
+        return syn::Error::new(field.span(), "`collection` cannot be combined with `group`.").to_compile_error().into()
+    }
+    let mut collection_methods = Vec::new();
+    for (field, attrs) in fields.iter().filter(|(_, attrs)| attrs.collection) {
+        let dest = destruct_ident(field, attrs);
+        let ty = destruct_type(field, attrs);
+        let key = destruct_key(field, attrs);
+        let method = match collection_kind(ty).as_ref().map(|(kind, types)| (*kind, types.as_slice())) {
+            Some(("Vec", [item])) => {
+                let push = Ident::new(&format!("push_{}", key), dest.span());
+                quote! {
+                    /// Appends `value` to the back of the field.
+                    pub fn #push(&mut self, value: #item) {
+                        self.#dest.push(value)
+                    }
+                }
+            },
+            Some(("HashSet", [item])) => {
+                let insert = Ident::new(&format!("insert_{}", key), dest.span());
+                quote! {
+                    /// Adds `value` to the field, returning whether it was newly inserted.
+                    pub fn #insert(&mut self, value: #item) -> bool {
+                        self.#dest.insert(value)
+                    }
+                }
+            },
+            Some(("HashMap", [key_ty, value_ty])) => {
+                let insert = Ident::new(&format!("insert_{}", key), dest.span());
+                quote! {
+                    /// Inserts the pair into the field, returning the value previously held by `key`.
+                    pub fn #insert(&mut self, key: #key_ty, value: #value_ty) -> ::core::option::Option<#value_ty> {
+                        self.#dest.insert(key, value)
+                    }
+                }
+            },
+            _ => return syn::Error::new(ty.span(), "`collection` requires a field of `Vec`, `HashSet` or `HashMap`.").to_compile_error().into()
+        };
+        collection_methods.push(method);
+    }
+
+    let unsafe_setters = fields.iter().filter(|(_, attrs)| attrs.unsafe_set).map(|(field, attrs)| {
+        let dest = destruct_ident(field, attrs);
+        let ty = destruct_type(field, attrs);
+        let setter = Ident::new(&format!("set_{}", dest.unraw()), dest.span());
+        quote! {
+            /// Overrides the skipped field, bypassing the encapsulation intended by `skip`.
+            ///
+            /// # Safety
+            /// This is memory-safe, and is only marked `unsafe` as an explicit escape hatch
+            /// for rare overrides. The caller must uphold the invariants the field is skipped for.
+            pub unsafe fn #setter(&mut self, value: #ty) {
+                self.#dest = value;
+            }
+        }
+    });
+
+    let edit = container.generates("edit").then(|| quote! {
+        /// Applies the changes in a limited closure and returns the Destruct structure as is,
+        /// so that several edits can be chained before `freeze()`.
+        pub fn edit(mut self, f: impl FnOnce(&mut Self)) -> Self {
+            f(&mut self);
+            self
+        }
+    });
+
+    let parse_error_ident = Ident::new(&format!("{}ParseError", generate), name.span());
+    // `Option` fields are parsed from the value inside, following the policy of `option_as` for `None`.
+    let parse_bounded = with_predicates(&container, generics, exposed.iter().flat_map(|(field, attrs)| {
+        let ty = destruct_type(field, attrs);
+        let ty = option_inner(ty).unwrap_or(ty);
+        [
+            parse_quote! { #ty: ::core::str::FromStr },
+            parse_quote! { <#ty as ::core::str::FromStr>::Err: ::core::fmt::Display },
+        ]
+    }));
+
+    let parse_error = (container.from_str || container.from_string_map).then(|| {
+        let error_doc = format!("Error returned when assigning the fields of [`{}`] from strings.", generate);
+        quote! {
+            #[doc = #error_doc]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #doc_hidden
+            pub enum #parse_error_ident {
+                /// There is no field with the given name.
+                UnknownField(String),
+                /// No value is given for the field.
+                MissingField(&'static str),
+                /// The value could not be parsed into the type of the field.
+                InvalidValue { field: &'static str, reason: String },
+            }
+
+            #[automatically_derived]
+            impl ::core::fmt::Display for #parse_error_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        Self::UnknownField(field) => write!(f, "unknown field `{}`", field),
+                        Self::MissingField(field) => write!(f, "missing field `{}`", field),
+                        Self::InvalidValue { field, reason } => write!(f, "invalid value for field `{}`: {}", field, reason),
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl ::std::error::Error for #parse_error_ident {}
+        }
+    });
+
+    // Parses `value` into the type of the field, reading the `None` of `option_as` for `Option` fields.
+    let parse_value = |(field, attrs): &(&Field, FieldAttributes)| {
+        let key = destruct_key(field, attrs);
+        let ty = destruct_type(field, attrs);
+        let parse = |ty: &Type| quote! {
+            value.parse::<#ty>().map_err(|e| #parse_error_ident::InvalidValue {
+                field: #key,
+                reason: e.to_string()
+            })?
+        };
+        match (option_inner(ty), container.option_as) {
+            (None, _) => parse(ty),
+            (Some(inner), OptionAs::Skip) => {
+                let parse = parse(inner);
+                quote! { Some(#parse) }
+            },
+            (Some(inner), policy) => {
+                let parse = parse(inner);
+                let none = if policy == OptionAs::Empty { "" } else { "null" };
+                quote! { if value == #none { None } else { Some(#parse) } }
+            }
+        }
+    };
+
+    let from_str = container.from_str.then(|| {
+        let (from_str_impl_generics, _, from_str_where_clause) = parse_bounded.split_for_impl();
+        let arms = exposed.iter().map(|member| {
+            let (field, attrs) = member;
+            let dest = destruct_ident(field, attrs);
+            let key = destruct_key(field, attrs);
+            let value = parse_value(member);
+            quote! {
+                #key => {
+                    self.#dest = #value;
+                }
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl #from_str_impl_generics #generate_ident #ty_generics #from_str_where_clause {
+                /// Parses `value` and assigns it to the field named `field`.
+                pub fn set_from_str(&mut self, field: &str, value: &str) -> Result<(), #parse_error_ident> {
+                    match field {
+                        #(#arms,)*
+                        _ => return Err(#parse_error_ident::UnknownField(field.to_string()))
+                    }
+                    Ok(())
+                }
+            }
+        }
+    });
+
+    let from_string_map = match container.from_string_map {
+        false => None,
+        true if !groups.is_empty() => return quote_spanned! { name.span() => compile_error!("`from_string_map` cannot be combined with `group`."); }.into(),
+        true => {
+            let bounded = with_predicates(&container, &parse_bounded, fields.iter()
+                .filter(|(_, attrs)| attrs.skip)
+                .map(|(field, attrs)| -> WherePredicate {
+                    let ty = destruct_type(field, attrs);
+                    parse_quote! { #ty: ::core::default::Default }
+                }));
+            let (map_impl_generics, _, map_where_clause) = bounded.split_for_impl();
+            let inits = fields.iter().map(|member| {
+                let (field, attrs) = member;
+                let dest = destruct_ident(field, attrs);
+                let key = destruct_key(field, attrs);
+                let cfgs = cfg_attrs(field);
+                if attrs.skip {
+                    return quote! {
+                        #(#cfgs)* #dest: ::core::default::Default::default()
+                    }
+                }
+                let value = parse_value(member);
+                let missing = match (option_inner(destruct_type(field, attrs)), container.option_as) {
+                    (Some(_), OptionAs::Skip) => quote! { None },
+                    _ => quote! { return Err(#parse_error_ident::MissingField(#key)) }
+                };
+                quote! {
+                    #(#cfgs)* #dest: match map.remove(#key) {
+                        Some(value) => #value,
+                        None => #missing
+                    }
+                }
+            });
+            Some(quote! {
+                #[automatically_derived]
+                impl #map_impl_generics ::core::convert::TryFrom<::std::collections::HashMap<String, String>> for #generate_ident #ty_generics #map_where_clause {
+                    type Error = #parse_error_ident;
+
+                    fn try_from(mut map: ::std::collections::HashMap<String, String>) -> Result<Self, Self::Error> {
+                        let destruct = Self { #(#inits,)* #(#hidden_inits,)* };
+                        if let Some(field) = map.into_keys().next() {
+                            return Err(#parse_error_ident::UnknownField(field))
+                        }
+                        Ok(destruct)
+                    }
+                }
+            })
+        }
+    };
+
+    let into_string_map = match container.into_string_map {
+        false => None,
+        true if !groups.is_empty() => return quote_spanned! { name.span() => compile_error!("`into_string_map` cannot be combined with `group`."); }.into(),
+        true => {
+            let bounded = with_predicates(&container, generics, exposed.iter().map(|(field, attrs)| -> WherePredicate {
+                let ty = destruct_type(field, attrs);
+                let ty = option_inner(ty).unwrap_or(ty);
+                parse_quote! { #ty: ::core::fmt::Display }
+            }));
+            let (_, _, map_where_clause) = bounded.split_for_impl();
+            let inserts = exposed.iter().map(|(field, attrs)| {
+                let dest = destruct_ident(field, attrs);
+                let key = destruct_key(field, attrs);
+                let cfgs = cfg_attrs(field);
+                let insert = match (option_inner(destruct_type(field, attrs)), container.option_as) {
+                    (None, _) => quote! {
+                        map.insert(#key.to_string(), self.#dest.to_string());
+                    },
+                    (Some(_), OptionAs::Skip) => quote! {
+                        if let Some(value) = self.#dest {
+                            map.insert(#key.to_string(), value.to_string());
+                        }
+                    },
+                    (Some(_), policy) => {
+                        let none = if policy == OptionAs::Empty { "" } else { "null" };
+                        quote! {
+                            map.insert(#key.to_string(), match self.#dest {
+                                Some(value) => value.to_string(),
+                                None => #none.to_string()
+                            });
+                        }
+                    }
+                };
+                quote! {
+                    #(#cfgs)*
+                    #insert
+                }
+            });
+            Some(quote! {
+                /// Converts the non-skipped fields into strings by `Display`, keyed by the field names.
+                pub fn into_string_map(self) -> ::std::collections::HashMap<String, String>
+                    #map_where_clause
+                {
+                    let mut map = ::std::collections::HashMap::new();
+                    #(#inserts)*
+                    map
+                }
+            })
+        }
+    };
+
+    let into_iter = if container.into_iter {
+        let ty = match homogeneous_type(exposed.iter().map(|(field, attrs)| destruct_type(field, attrs)), "into_iter", name) {
+            Ok(ty) => ty,
+            Err(e) => return e.to_compile_error().into()
+        };
+        let len = exposed.len();
+        let values = exposed.iter().map(|(field, attrs)| destruct_ident(field, attrs)).collect::<Vec<_>>();
+        // The borrowing counterpart lets `for value in &des` iterate without consuming the Destruct structure.
+        let lifetime = fresh_lifetime(generics, "'__destruct_iter_lifetime");
+        let generics_with_lt = with_lifetime(generics, &lifetime);
+        let (impl_generics_with_lt, _, _) = generics_with_lt.split_for_impl();
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics IntoIterator for #generate_ident #ty_generics #where_clause {
+                type Item = #ty;
+                type IntoIter = ::core::array::IntoIter<#ty, #len>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    [#(self.#values,)*].into_iter()
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics_with_lt IntoIterator for &#lifetime #generate_ident #ty_generics #where_clause {
+                type Item = &#lifetime #ty;
+                type IntoIter = ::core::array::IntoIter<&#lifetime #ty, #len>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    [#(&self.#values,)*].into_iter()
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let index_usize = if container.index_usize {
+        let ty = match homogeneous_type(exposed.iter().map(|(field, attrs)| destruct_type(field, attrs)), "index_usize", name) {
+            Ok(ty) => ty,
+            Err(e) => return e.to_compile_error().into()
+        };
+        let len = exposed.len();
+        let indices = 0..len;
+        let values = exposed.iter().map(|(field, attrs)| destruct_ident(field, attrs)).collect::<Vec<_>>();
+        let arms = indices.clone().zip(&values).map(|(index, value)| quote! { #index => &self.#value });
+        let arms_mut = indices.zip(&values).map(|(index, value)| quote! { #index => &mut self.#value });
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::ops::Index<usize> for #generate_ident #ty_generics #where_clause {
+                type Output = #ty;
+
+                fn index(&self, index: usize) -> &Self::Output {
+                    match index {
+                        #(#arms,)*
+                        _ => panic!("index out of bounds: the len is {} but the index is {}", #len, index)
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::core::ops::IndexMut<usize> for #generate_ident #ty_generics #where_clause {
+                fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                    match index {
+                        #(#arms_mut,)*
+                        _ => panic!("index out of bounds: the len is {} but the index is {}", #len, index)
+                    }
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let fold = if container.fold {
+        let ty = match homogeneous_type(exposed.iter().map(|(field, attrs)| destruct_type(field, attrs)), "fold", name) {
+            Ok(ty) => ty,
+            Err(e) => return e.to_compile_error().into()
+        };
+        let values = exposed.iter().map(|(field, attrs)| destruct_ident(field, attrs));
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics #generate_ident #ty_generics #where_clause {
+                /// Folds every field value into an accumulator in declaration order.
+                pub fn fold<A>(self, init: A, f: impl FnMut(A, #ty) -> A) -> A {
+                    [#(self.#values,)*].into_iter().fold(init, f)
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let visible_fields = exposed.iter().any(|(_, attrs)| attrs.skip_if.is_some()).then(|| {
+        let pushes = exposed.iter().map(|(field, attrs)| {
+            let key = destruct_key(field, attrs);
+            match &attrs.skip_if {
+                Some(predicate) => quote! {
+                    if !#predicate(self) {
+                        fields.push(#key);
+                    }
+                },
+                None => quote! {
+                    fields.push(#key);
+                }
+            }
+        });
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #generate_ident #ty_generics #where_clause {
+                /// Returns the names of the fields, except those whose `skip_if` predicate currently holds.
+                pub fn visible_fields(&self) -> Vec<&'static str> {
+                    let mut fields = Vec::new();
+                    #(#pushes)*
+                    fields
+                }
+            }
+        }
+    });
+
+    if let Some((field, _)) = members.iter().find(|(_, attrs)| attrs.validate.is_some() && attrs.group.is_some()) {
+        return syn::Error::new(field.span(), "`validate` cannot be combined with `group`.").to_compile_error().into()
+    }
+    if let Some((field, _)) = fields.iter().find(|(_, attrs)| attrs.validate.is_some() && !container.validators) {
+        return syn::Error::new(field.span(), "`validate` requires `#[destructure(validators)]` on the structure.").to_compile_error().into()
+    }
+    let validate_all = container.validators.then(|| {
+        let results = fields.iter().filter_map(|(field, attrs)| {
+            let validator = attrs.validate.as_ref()?;
+            let dest = destruct_ident(field, attrs);
+            let key = destruct_key(field, attrs);
+            let cfgs = cfg_attrs(field);
+            Some(quote! {
+                #(#cfgs)*
+                results.push((#key, #validator(&self.#dest)));
+            })
+        });
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #generate_ident #ty_generics #where_clause {
+                /// Runs the `validate` function of each field, returning the results keyed by the field names.
+                pub fn validate_all(&self) -> Vec<(&'static str, ::core::result::Result<(), String>)> {
+                    let mut results = Vec::new();
+                    #(#results)*
+                    results
+                }
+            }
+        }
+    });
+
+    let as_mut_view = container.as_mut_view.then(|| {
+        let view_ident = Ident::new(&format!("{}Mut", generate), name.span());
+        let lifetime = fresh_lifetime(generics, "'__origin_destruct_lifetime");
+        let generics_with_lt = with_lifetime(generics, &lifetime);
+        let generics_with_anonymous = with_lifetime(generics, &Lifetime::new("'_", generics.span()));
+        let (_, ty_generics_with_anonymous, _) = generics_with_anonymous.split_for_impl();
+        let view = fields.iter().map(|(field, attrs)| {
+            let dest = destruct_ident(field, attrs);
+            let ty = destruct_type(field, attrs);
+            let vis = (!attrs.skip).then(|| quote! { pub });
+            quote! {
+                #vis #dest: &#lifetime mut #ty
+            }
+        });
+        let borrowed = fields.iter().map(|(field, attrs)| {
+            let dest = destruct_ident(field, attrs);
+            quote! {
+                #dest: &mut self.#dest
+            }
+        });
+        quote! {
+            /// Do not have an explicit implementation for this structure.
+            #doc_hidden
+            pub struct #view_ident #generics_with_lt #where_clause {
+                #(#view,)*
+            }
+
+            #[automatically_derived]
+            impl #impl_generics #generate_ident #ty_generics #where_clause {
+                /// Borrows every field mutably, so they can be modified in bulk before `freeze()`.
+                pub fn as_mut_view(&mut self) -> #view_ident #ty_generics_with_anonymous {
+                    #view_ident { #(#borrowed,)* }
+                }
+            }
+        }
+    });
+
+    let debug = container.debug.then(|| {
+        let shown = fields.iter().filter(|(_, attrs)| !attrs.skip || attrs.redact).collect::<Vec<_>>();
+        let bounded = with_predicates(&container, generics, shown.iter()
+            .filter(|(_, attrs)| !attrs.redact)
+            .map(|(field, attrs)| {
+                let ty = destruct_type(field, attrs);
+                parse_quote! { #ty: ::core::fmt::Debug }
+            }));
+        let (debug_impl_generics, _, debug_where_clause) = bounded.split_for_impl();
+        let entries = shown.iter().map(|(field, attrs)| {
+            let dest = destruct_ident(field, attrs);
+            let key = destruct_key(field, attrs);
+            if attrs.redact {
+                quote! { .field(#key, &::core::format_args!("<redacted>")) }
+            } else {
+                quote! { .field(#key, &self.#dest) }
+            }
+        });
+        let finish = if shown.len() == fields.len() {
+            quote! { finish }
+        } else {
+            quote! { finish_non_exhaustive }
+        };
+        quote! {
+            #[automatically_derived]
+            impl #debug_impl_generics ::core::fmt::Debug for #generate_ident #ty_generics #debug_where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.debug_struct(#generate)
+                        #(#entries)*
+                        .#finish()
+                }
+            }
+        }
+    });
+
+    if let Some((field, _)) = members.iter().find(|(_, attrs)| attrs.deep_clone && !container.clone) {
+        return syn::Error::new(field.span(), "`deep_clone` requires `#[destructure(clone)]` on the structure.").to_compile_error().into()
+    }
+    if let Some((field, _)) = members.iter().find(|(field, attrs)| attrs.deep_clone && shared_pointee(destruct_type(field, attrs)).is_none()) {
+        return syn::Error::new(field.span(), "`deep_clone` can only be used on `Arc` or `Rc` fields.").to_compile_error().into()
+    }
+    let clone = match container.clone {
+        true if !groups.is_empty() => return quote_spanned! { name.span() => compile_error!("`clone` cannot be combined with `group`."); }.into(),
+        true => {
+            let bounded = with_predicates(&container, generics, fields.iter().map(|(field, attrs)| {
+                let ty = destruct_type(field, attrs);
+                match attrs.deep_clone {
+                    true => {
+                        let pointee = shared_pointee(ty);
+                        parse_quote! { #pointee: ::core::clone::Clone }
+                    },
+                    false => parse_quote! { #ty: ::core::clone::Clone }
+                }
+            }));
+            let (clone_impl_generics, _, clone_where_clause) = bounded.split_for_impl();
+            let clones = fields.iter().map(|(field, attrs)| {
+                let dest = destruct_ident(field, attrs);
+                let ty = destruct_type(field, attrs);
+                let cfgs = cfg_attrs(field);
+                match attrs.deep_clone {
+                    true => quote! { #(#cfgs)* #dest: <#ty>::new(::core::clone::Clone::clone(&*self.#dest)) },
+                    false => quote! { #(#cfgs)* #dest: ::core::clone::Clone::clone(&self.#dest) }
+                }
+            });
+            Some(quote! {
+                #[automatically_derived]
+                impl #clone_impl_generics ::core::clone::Clone for #generate_ident #ty_generics #clone_where_clause {
+                    fn clone(&self) -> Self {
+                        Self { #(#clones,)* #(#hidden_inits,)* }
+                    }
+                }
+            })
+        },
+        false => None
+    };
+
+    let display = match &container.display {
+        Some(template) => {
+            let (rewritten, placeholders) = match template_placeholders(template) {
+                Ok(parsed) => parsed,
+                Err(e) => return e.to_compile_error().into()
+            };
+            let mut args = Vec::new();
+            for placeholder in &placeholders {
+                match referenced_field(&fields, placeholder, template, "display", &generate) {
+                    Ok(found) => args.push(found),
+                    Err(e) => return e.to_compile_error().into()
+                }
+            }
+            let bounded = with_predicates(&container, generics, args.iter().map(|(field, attrs)| {
+                let ty = destruct_type(field, attrs);
+                parse_quote! { #ty: ::core::fmt::Display }
+            }));
+            let (display_impl_generics, _, display_where_clause) = bounded.split_for_impl();
+            let args = args.iter().map(|(field, attrs)| {
+                let dest = destruct_ident(field, attrs);
+                let key = Ident::new(&format!("__{}", destruct_key(field, attrs)), dest.span());
+                quote! { #key = self.#dest }
+            });
+            Some(quote! {
+                #[automatically_derived]
+                impl #display_impl_generics ::core::fmt::Display for #generate_ident #ty_generics #display_where_clause {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        ::core::write!(f, #rewritten, #(#args),*)
+                    }
+                }
+            })
+        },
+        None => None
+    };
+
+    let arbitrary = match container.arbitrary {
+        false => None,
+        true if cfg!(not(feature = "arbitrary")) => return quote_spanned! { name.span() => compile_error!("`arbitrary` requires the `arbitrary` feature of `destructure` to be enabled."); }.into(),
+        true if !groups.is_empty() => return quote_spanned! { name.span() => compile_error!("`arbitrary` cannot be combined with `group`."); }.into(),
+        true => {
+            let lifetime = fresh_lifetime(generics, "'__arbitrary_lifetime");
+            let mut bounded = with_predicates(&container, generics, fields.iter().map(|(field, attrs)| {
+                let ty = destruct_type(field, attrs);
+                parse_quote! { #ty: ::arbitrary::Arbitrary<#lifetime> }
+            }));
+            bounded = with_lifetime(&bounded, &lifetime);
+            let (arbitrary_impl_generics, _, arbitrary_where_clause) = bounded.split_for_impl();
+            let inits = fields.iter().map(|(field, attrs)| {
+                let dest = destruct_ident(field, attrs);
+                let cfgs = cfg_attrs(field);
+                quote! {
+                    #(#cfgs)* #dest: ::arbitrary::Arbitrary::arbitrary(u)?
+                }
+            });
+            Some(quote! {
+                #[automatically_derived]
+                impl #arbitrary_impl_generics ::arbitrary::Arbitrary<#lifetime> for #generate_ident #ty_generics #arbitrary_where_clause {
+                    fn arbitrary(u: &mut ::arbitrary::Unstructured<#lifetime>) -> ::arbitrary::Result<Self> {
+                        Ok(Self { #(#inits,)* #(#hidden_inits,)* })
+                    }
+                }
+            })
+        }
+    };
+
+    if !container.adapts.is_empty() && !groups.is_empty() {
+        return quote_spanned! { name.span() => compile_error!("`adapt` cannot be combined with `group`."); }.into()
+    }
+    let from = container.from.then(|| {
+        let back = match &freeze_error {
+            Some(error) => quote! {
+                #[automatically_derived]
+                impl #impl_generics ::core::convert::TryFrom<#generate_ident #ty_generics> for #name #ty_generics #where_clause {
+                    type Error = #error;
+
+                    fn try_from(destruct: #generate_ident #ty_generics) -> Result<Self, Self::Error> {
+                        destruct.try_freeze()
+                    }
+                }
+            },
+            None => quote! {
+                #[automatically_derived]
+                impl #impl_generics ::core::convert::From<#generate_ident #ty_generics> for #name #ty_generics #where_clause {
+                    fn from(destruct: #generate_ident #ty_generics) -> Self {
+                        destruct.freeze()
+                    }
+                }
+            }
+        };
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::From<#name #ty_generics> for #generate_ident #ty_generics #where_clause {
+                fn from(origin: #name #ty_generics) -> Self {
+                    origin.into_destruct()
+                }
+            }
+
+            #back
+        }
+    });
+
+    let staged = match &container.staged {
+        Some(required) => {
+            if !generics.params.is_empty() {
+                return quote_spanned! { name.span() => compile_error!("`staged` is not supported on generic structures."); }.into()
+            }
+            let mut stages = Vec::new();
+            for ident in required {
+                let key = ident.unraw().to_string();
+                if stages.iter().any(|(other, _): &(&Ident, _)| other.unraw() == key) {
+                    return syn::Error::new(ident.span(), format!("`{}` is required more than once.", key)).to_compile_error().into()
+                }
+                match referenced_field(&fields, &key, ident, "staged", &generate) {
+                    Ok(found) => stages.push((ident, found)),
+                    Err(e) => return e.to_compile_error().into()
+                }
+            }
+            let staged_ident = Ident::new(&format!("{}Staged", generate), name.span());
+            let unset_ident = Ident::new(&format!("{}Unset", generate), name.span());
+            let set_ident = Ident::new(&format!("{}Set", generate), name.span());
+            let params = (0..stages.len()).map(|i| Ident::new(&format!("__S{}", i), name.span())).collect::<Vec<_>>();
+            let unsets = stages.iter().map(|_| &unset_ident);
+            let sets = stages.iter().map(|_| &set_ident).collect::<Vec<_>>();
+            let setters = stages.iter().enumerate().map(|(i, (_, (field, attrs)))| {
+                let dest = destruct_ident(field, attrs);
+                let ty = destruct_type(field, attrs);
+                let setter = Ident::new(&format!("set_{}", destruct_key(field, attrs)), dest.span());
+                let states = params.iter().enumerate().map(|(j, param)| match i == j {
+                    true => quote! { #set_ident },
+                    false => quote! { #param }
+                });
+                quote! {
+                    /// Assigns the required field, marking it as set.
+                    pub fn #setter(mut self, value: #ty) -> #staged_ident<#(#states),*> {
+                        self.destruct.#dest = value;
+                        #staged_ident { destruct: self.destruct, state: ::core::marker::PhantomData }
+                    }
+                }
+            });
+            let (freeze, reconstruct_staged) = match &freeze_error {
+                Some(error) => (
+                    quote! {
+                        /// Restore the Destruct structure to its original structure again, once every required field is set.
+                        #[track_caller]
+                        pub fn try_freeze(self) -> Result<#name, #error> {
+                            self.destruct.try_freeze()
+                        }
+                    },
+                    quote! {
+                        /// Same as `try_reconstruct()`, but `f` must call the setter of every required field.
+                        #[track_caller]
+                        pub fn try_reconstruct_staged(self, f: impl FnOnce(#staged_ident<#(#unsets),*>) -> #staged_ident<#(#sets),*>) -> Result<Self, #error> {
+                            f(self.into_staged()).try_freeze()
+                        }
+                    }
+                ),
+                None => (
+                    quote! {
+                        /// Restore the Destruct structure to its original structure again, once every required field is set.
+                        #[track_caller]
+                        pub fn freeze(self) -> #name {
+                            self.destruct.freeze()
+                        }
+                    },
+                    quote! {
+                        /// Same as `reconstruct()`, but `f` must call the setter of every required field.
+                        #[track_caller]
+                        pub fn reconstruct_staged(self, f: impl FnOnce(#staged_ident<#(#unsets),*>) -> #staged_ident<#(#sets),*>) -> Self {
+                            f(self.into_staged()).freeze()
+                        }
+                    }
+                )
+            };
+            let unsets = stages.iter().map(|_| &unset_ident);
+            let staged_doc = format!("[`{}`] which can only be frozen after the setters of the required fields are called.", generate);
+            Some(quote! {
+                /// State of a required field whose setter has not been called yet.
+                #doc_hidden
+                pub struct #unset_ident;
+
+                /// State of a required field whose setter has been called.
+                #doc_hidden
+                pub struct #set_ident;
+
+                #[doc = #staged_doc]
+                #doc_hidden
+                pub struct #staged_ident<#(#params),*> {
+                    destruct: #generate_ident,
+                    state: ::core::marker::PhantomData<(#(#params,)*)>,
+                }
+
+                #[automatically_derived]
+                impl<#(#params),*> #staged_ident<#(#params),*> {
+                    #(#setters)*
+
+                    /// Borrows the Destruct structure.
+                    pub fn destruct(&self) -> &#generate_ident {
+                        &self.destruct
+                    }
+
+                    /// Borrows the Destruct structure mutably, e.g. to edit the fields which are not required.
+                    pub fn destruct_mut(&mut self) -> &mut #generate_ident {
+                        &mut self.destruct
+                    }
+                }
+
+                #[automatically_derived]
+                impl #staged_ident<#(#sets),*> {
+                    #freeze
+                }
+
+                #[automatically_derived]
+                impl #name {
+                    /// Decompose the field values into the staged Destruct structure, where no required field is set yet.
+                    pub fn into_staged(self) -> #staged_ident<#(#unsets),*> {
+                        #staged_ident { destruct: self.into_destruct(), state: ::core::marker::PhantomData }
+                    }
+
+                    #reconstruct_staged
+                }
+            })
+        },
+        None => None
+    };
+
+    let mut adapts = Vec::new();
+    for Adapt { target, map } in &container.adapts {
+        for (from, _) in map {
+            if let Err(e) = referenced_field(&fields, &from.unraw().to_string(), from, "adapt", &generate) {
+                return e.to_compile_error().into()
+            }
+        }
+        let assigns = exposed.iter().map(|(field, attrs)| {
+            let dest = destruct_ident(field, attrs);
+            let to = map.iter()
+                .find(|(from, _)| from.unraw() == dest.unraw())
+                .map_or(dest, |(_, to)| to);
+            quote! {
+                #to: destruct.#dest
+            }
+        });
+        adapts.push(quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::From<#name #ty_generics> for #target #where_clause {
+                fn from(value: #name #ty_generics) -> Self {
+                    let destruct = value.into_destruct();
+                    Self { #(#assigns,)* }
+                }
+            }
+        });
+    }
+
+    let default_fields = match container.default_fields {
+        true if !groups.is_empty() => return quote_spanned! { name.span() => compile_error!("`default_fields` cannot be combined with `group`."); }.into(),
+        true => {
+            let bounded = with_predicates(&container, generics, fields.iter().map(|(field, attrs)| {
+                let ty = destruct_type(field, attrs);
+                parse_quote! { #ty: ::core::default::Default }
+            }));
+            let (default_impl_generics, _, default_where_clause) = bounded.split_for_impl();
+            let defaults = fields.iter().map(|(field, attrs)| {
+                let dest = destruct_ident(field, attrs);
+                let cfgs = cfg_attrs(field);
+                quote! { #(#cfgs)* #dest: ::core::default::Default::default() }
+            });
+            Some(quote! {
+                #[automatically_derived]
+                impl #default_impl_generics ::core::default::Default for #generate_ident #ty_generics #default_where_clause {
+                    fn default() -> Self {
+                        Self { #(#defaults,)* #(#hidden_inits,)* }
+                    }
+                }
+            })
+        },
+        false => None
+    };
+
+    let as_tuple = match container.as_tuple {
+        true if !groups.is_empty() => return quote_spanned! { name.span() => compile_error!("`as_tuple` cannot be combined with `group`."); }.into(),
+        true => {
+            if let Some((field, _)) = fields.iter().find(|(field, _)| cfg_attrs(field).next().is_some()) {
+                return syn::Error::new(field.span(), "`as_tuple` cannot be used with `#[cfg]` fields, since tuples have no conditional elements.").to_compile_error().into()
+            }
+            let skipped = fields.iter().filter(|(_, attrs)| attrs.skip).collect::<Vec<_>>();
+            let bounded = with_predicates(&container, generics, skipped.iter().map(|(field, attrs)| {
+                let ty = destruct_type(field, attrs);
+                parse_quote! { #ty: ::core::default::Default }
+            }));
+            let (_, _, tuple_where_clause) = bounded.split_for_impl();
+            let dests = exposed.iter().map(|(field, attrs)| destruct_ident(field, attrs)).collect::<Vec<_>>();
+            let tys = exposed.iter().map(|(field, attrs)| destruct_type(field, attrs)).collect::<Vec<_>>();
+            let defaults = skipped.iter().map(|(field, attrs)| {
+                let dest = destruct_ident(field, attrs);
+                quote! { #dest: ::core::default::Default::default() }
+            });
+            Some(quote! {
+                /// Converts the non-skipped fields into a tuple in field order.
+                pub fn into_tuple(self) -> (#(#tys,)*) {
+                    (#(self.#dests,)*)
+                }
+
+                /// Builds the Destruct structure from a tuple of the non-skipped fields in field order,
+                /// filling skipped fields by `Default`.
+                pub fn from_tuple((#(#dests,)*): (#(#tys,)*)) -> Self
+                    #tuple_where_clause
+                {
+                    Self { #(#dests,)* #(#defaults,)* #(#hidden_inits,)* }
+                }
+            })
+        },
+        false => None
+    };
+
+    let as_refs = match container.as_refs {
+        true if !groups.is_empty() => return quote_spanned! { name.span() => compile_error!("`as_refs` cannot be combined with `group`."); }.into(),
+        true => {
+            if let Some((field, _)) = exposed.iter().find(|(field, _)| cfg_attrs(field).next().is_some()) {
+                return syn::Error::new(field.span(), "`as_refs` cannot be used with `#[cfg]` fields, since tuples have no conditional elements.").to_compile_error().into()
+            }
+            let dests = exposed.iter().map(|(field, attrs)| destruct_ident(field, attrs));
+            let tys = exposed.iter().map(|(field, attrs)| destruct_type(field, attrs));
+            Some(quote! {
+                /// Borrows the non-skipped fields as a tuple of references in field order.
+                pub fn as_refs(&self) -> (#(&#tys,)*) {
+                    (#(&self.#dests,)*)
+                }
+            })
+        },
+        false => None
+    };
+
+    let into_tuple = container.as_tuple.then(|| {
+        let dests = exposed.iter().map(|(field, attrs)| destruct_ident(field, attrs));
+        let tys = exposed.iter().map(|(field, attrs)| destruct_type(field, attrs));
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::From<#generate_ident #ty_generics> for (#(#tys,)*) #where_clause {
+                fn from(value: #generate_ident #ty_generics) -> Self {
+                    (#(value.#dests,)*)
+                }
+            }
+        }
+    });
+
+    // `Destruct{Name}Patch` is shared by `with_defaults` and `diff_patch`.
+    let patch_ident = Ident::new(&format!("{}Patch", generate), name.span());
+    let overlays = exposed.iter().map(|(field, attrs)| {
+        let dest = destruct_ident(field, attrs);
+        quote! {
+            if let ::core::option::Option::Some(value) = patch.#dest {
+                destruct.#dest = value;
+            }
+        }
+    }).collect::<Vec<_>>();
+    let patch_phantom = phantom_type(generics, exposed.iter().map(|(field, attrs)| destruct_type(field, attrs)));
+    let (patch_phantom_field, patch_phantom_init) = match &patch_phantom {
+        Some(phantom) => (
+            Some(quote! { __destruct_phantom: #phantom, }),
+            Some(quote! { __destruct_phantom: ::core::marker::PhantomData, })
+        ),
+        None => (None, None)
+    };
+    let patch = (container.with_defaults || container.diff_patch).then(|| {
+        let patch_doc = format!("Fields to override on [`{}`], where `None` keeps the current value.", generate);
+        let patch_fields = exposed.iter().map(|(field, attrs)| {
+            let dest = destruct_ident(field, attrs);
+            let ty = destruct_type(field, attrs);
+            quote! {
+                pub #dest: ::core::option::Option<#ty>
+            }
+        });
+        let nones = exposed.iter().map(|(field, attrs)| destruct_ident(field, attrs));
+        quote! {
+            #[doc = #patch_doc]
+            #doc_hidden
+            pub struct #patch_ident #generics #where_clause {
+                #(#patch_fields,)*
+                #patch_phantom_field
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::core::default::Default for #patch_ident #ty_generics #where_clause {
+                fn default() -> Self {
+                    Self { #(#nones: ::core::option::Option::None,)* #patch_phantom_init }
+                }
+            }
+        }
+    });
+
+    let with_defaults = container.with_defaults.then(|| {
+        let bounded = with_predicates(&container, generics, [parse_quote! { #name #ty_generics: ::core::default::Default }]);
+        let (defaults_impl_generics, _, defaults_where_clause) = bounded.split_for_impl();
+        quote! {
+            #[automatically_derived]
+            impl #defaults_impl_generics #generate_ident #ty_generics #defaults_where_clause {
+                /// Starts from the `Default` of the original structure and overrides the fields given by `patch`.
+                pub fn with_defaults(patch: #patch_ident #ty_generics) -> Self {
+                    let mut destruct = <#name #ty_generics as ::core::default::Default>::default().into_destruct();
+                    #(#overlays)*
+                    destruct
+                }
+            }
+        }
+    });
+
+    let diff_patch = container.diff_patch.then(|| {
+        let bounded = with_predicates(&container, generics, exposed.iter().map(|(field, attrs)| {
+            let ty = destruct_type(field, attrs);
+            parse_quote! { #ty: ::core::cmp::PartialEq + ::core::clone::Clone }
+        }));
+        let (diff_impl_generics, _, diff_where_clause) = bounded.split_for_impl();
+        let changes = exposed.iter().map(|(field, attrs)| {
+            let dest = destruct_ident(field, attrs);
+            quote! {
+                #dest: (self.#dest != other.#dest).then(|| ::core::clone::Clone::clone(&other.#dest))
+            }
+        });
+        quote! {
+            #[automatically_derived]
+            impl #diff_impl_generics #generate_ident #ty_generics #diff_where_clause {
+                /// Returns the patch turning `self` into `other`, where only the changed fields are `Some`.
+                pub fn diff_patch(&self, other: &Self) -> #patch_ident #ty_generics {
+                    #patch_ident { #(#changes,)* #patch_phantom_init }
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics #generate_ident #ty_generics #where_clause {
+                /// Overrides the fields given as `Some` by `patch`, e.g. one computed by [`diff_patch()`](Self::diff_patch).
+                pub fn apply_patch(&mut self, patch: #patch_ident #ty_generics) {
+                    let destruct = self;
+                    #(#overlays)*
+                }
+            }
+        }
+    });
+
+    let schema = container.schema.then(|| {
+        let entries = exposed.iter().map(|(field, attrs)| {
+            let key = destruct_key(field, attrs);
+            let ty = destruct_type(field, attrs);
+            let cfgs = cfg_attrs(field);
+            quote! { #(#cfgs)* entries.push((#key, ::core::any::type_name::<#ty>())); }
+        });
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #generate_ident #ty_generics #where_clause {
+                /// Returns the name of each field paired with the name of its type, except the skipped fields.
+                pub fn schema() -> Vec<(&'static str, &'static str)> {
+                    #[allow(unused_mut)]
+                    let mut entries = Vec::new();
+                    #(#entries)*
+                    entries
+                }
+            }
+        }
+    });
+
+    let field_names = container.field_names.then(|| {
+        let count = exposed.len();
+        let keys = exposed.iter().map(|(field, attrs)| destruct_key(field, attrs));
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #generate_ident #ty_generics #where_clause {
+                /// Number of the fields except the skipped ones, usable as the length of an array.
+                pub const FIELD_COUNT: usize = #count;
+
+                /// Names of the fields except the skipped ones, in the order of the Destruct structure.
+                pub const FIELD_NAMES: [&'static str; #count] = [#(#keys),*];
+            }
+        }
+    });
+
+    let size_hint = container.size_hint.then(|| {
+        let sizes = exposed.iter().map(|(field, attrs)| {
+            let ty = destruct_type(field, attrs);
+            let cfgs = cfg_attrs(field);
+            quote! { #(#cfgs)* { size += ::core::mem::size_of::<#ty>(); } }
+        });
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #generate_ident #ty_generics #where_clause {
+                /// Returns the sum of the sizes of the field types, as a hint for preallocating buffers.
+                pub fn encoded_size_hint(&self) -> usize {
+                    #[allow(unused_mut)]
+                    let mut size = 0;
+                    #(#sizes)*
+                    size
+                }
+            }
+        }
+    });
+
+    let checksum = container.checksum.then(|| {
+        let bounded = with_predicates(&container, generics, exposed.iter().map(|(field, attrs)| {
+            let ty = destruct_type(field, attrs);
+            parse_quote! { #ty: ::core::hash::Hash }
+        }));
+        let (checksum_impl_generics, _, checksum_where_clause) = bounded.split_for_impl();
+        let hashes = exposed.iter().map(|(field, attrs)| {
+            let dest = destruct_ident(field, attrs);
+            let cfgs = cfg_attrs(field);
+            quote! { #(#cfgs)* ::core::hash::Hash::hash(&self.#dest, &mut hasher); }
+        });
+        quote! {
+            #[automatically_derived]
+            impl #checksum_impl_generics #generate_ident #ty_generics #checksum_where_clause {
+                /// Returns the hash of the non-skipped fields, fed into a `DefaultHasher` in order.
+                pub fn checksum(&self) -> u64 {
+                    let mut hasher = ::std::collections::hash_map::DefaultHasher::new();
+                    #(#hashes)*
+                    ::core::hash::Hasher::finish(&hasher)
+                }
+            }
+        }
+    });
+
+    let reflect = container.reflect.then(|| {
+        let meta_ident = Ident::new(&format!("{}FieldMeta", generate), name.span());
+        let meta_doc = format!("Description of a field of [`{}`] returned by `reflect()`.", generate);
+        let bounded = with_predicates(&container, generics, exposed.iter().map(|(field, attrs)| {
+            let ty = destruct_type(field, attrs);
+            parse_quote! { #ty: ::core::fmt::Display }
+        }));
+        let (reflect_impl_generics, _, reflect_where_clause) = bounded.split_for_impl();
+        let metas = exposed.iter().map(|(field, attrs)| {
+            let dest = destruct_ident(field, attrs);
+            let key = destruct_key(field, attrs);
+            let ty = destruct_type(field, attrs);
+            let cfgs = cfg_attrs(field);
+            quote! {
+                #(#cfgs)*
+                metas.push(#meta_ident {
+                    name: #key,
+                    type_name: ::core::any::type_name::<#ty>(),
+                    value: ::std::string::ToString::to_string(&self.#dest),
+                });
+            }
+        });
+        quote! {
+            #[doc = #meta_doc]
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            #doc_hidden
+            pub struct #meta_ident {
+                /// Name of the field.
+                pub name: &'static str,
+                /// Name of the type of the field, given by `std::any::type_name`.
+                pub type_name: &'static str,
+                /// Value of the field formatted by `Display`.
+                pub value: String,
+            }
+
+            #[automatically_derived]
+            impl #reflect_impl_generics #generate_ident #ty_generics #reflect_where_clause {
+                /// Describes each field except the skipped ones, e.g. for rendering a form of the fields.
+                pub fn reflect(&self) -> Vec<#meta_ident> {
+                    #[allow(unused_mut)]
+                    let mut metas = Vec::new();
+                    #(#metas)*
+                    metas
+                }
+            }
+        }
+    });
+
+    let field_enum = match container.field_enum {
+        true if !generics.params.is_empty() => return quote_spanned! { name.span() => compile_error!("`field_enum` is not supported on generic structures."); }.into(),
+        true => {
+            let value_ident = Ident::new(&format!("{}FieldValue", generate), name.span());
+            let error_ident = Ident::new(&format!("{}SetError", generate), name.span());
+            let value_doc = format!("Value of any public field of [`{}`], passed to `set()`.", generate);
+            let error_doc = format!("Error returned when assigning a field of [`{}`] by its name.", generate);
+            let variants = exposed.iter().map(|(field, attrs)| {
+                let ty = destruct_type(field, attrs);
+                let variant = Ident::new(&to_pascal_case(&destruct_key(field, attrs)), field.span());
+                let doc = format!("Value of `{}`.", destruct_key(field, attrs));
+                let cfgs = cfg_attrs(field);
+                quote! {
+                    #[doc = #doc]
+                    #(#cfgs)*
+                    #variant(#ty)
+                }
+            });
+            let arms = exposed.iter().map(|(field, attrs)| {
+                let dest = destruct_ident(field, attrs);
+                let key = destruct_key(field, attrs);
+                let variant = Ident::new(&to_pascal_case(&key), field.span());
+                let cfgs = cfg_attrs(field);
+                quote! {
+                    #(#cfgs)*
+                    (#key, #value_ident::#variant(value)) => self.#dest = value,
+                }
+            });
+            let mismatches = exposed.iter().map(|(field, attrs)| {
+                let key = destruct_key(field, attrs);
+                let ty = destruct_type(field, attrs);
+                let cfgs = cfg_attrs(field);
+                quote! {
+                    #(#cfgs)*
+                    #key => (#key, ::core::any::type_name::<#ty>()),
+                }
+            });
+            Some(quote! {
+                #[doc = #value_doc]
+                #doc_hidden
+                pub enum #value_ident {
+                    #(#variants,)*
+                }
+
+                #[doc = #error_doc]
+                #[derive(Debug, Clone, PartialEq, Eq)]
+                #doc_hidden
+                pub enum #error_ident {
+                    /// There is no public field with the given name.
+                    UnknownField(String),
+                    /// The value is not of the type of the field.
+                    TypeMismatch { field: &'static str, expected: &'static str },
+                }
+
+                #[automatically_derived]
+                impl ::core::fmt::Display for #error_ident {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        match self {
+                            Self::UnknownField(field) => write!(f, "unknown field `{}`", field),
+                            Self::TypeMismatch { field, expected } => write!(f, "field `{}` expects a value of `{}`", field, expected),
+                        }
+                    }
+                }
+
+                #[automatically_derived]
+                impl ::std::error::Error for #error_ident {}
+
+                #[automatically_derived]
+                impl #generate_ident {
+                    /// Assigns `value` to the field named `name`, failing if there is no such field or the type does not match.
+                    pub fn set(&mut self, name: &str, value: #value_ident) -> Result<(), #error_ident> {
+                        match (name, value) {
+                            #(#arms)*
+                            (name, _) => {
+                                let (field, expected) = match name {
+                                    #(#mismatches)*
+                                    _ => return Err(#error_ident::UnknownField(name.to_string()))
+                                };
+                                return Err(#error_ident::TypeMismatch { field, expected })
+                            }
+                        }
+                        Ok(())
+                    }
+                }
+            })
+        },
+        false => None
+    };
+
+    let map_all = if container.map_all {
+        let param = match single_type_param(generics, fields.iter().map(|(field, attrs)| destruct_type(field, attrs))) {
+            Some(param) => param,
+            None => return quote_spanned! { name.span() => compile_error!("`map_all` requires a single type parameter without where clause, which every field has as its type."); }.into()
+        };
+        let ident = &param.ident;
+        let bounds = &param.bounds;
+        let mapped = fields.iter().map(|(field, attrs)| {
+            let dest = destruct_ident(field, attrs);
+            let cfgs = cfg_attrs(field);
+            quote! {
+                #(#cfgs)* #dest: f(self.#dest)
+            }
+        });
+        Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics #generate_ident #ty_generics #where_clause {
+                /// Converts every field by `f`, changing the type parameter of the Destruct structure.
+                pub fn map_all<__U: #bounds>(self, mut f: impl FnMut(#ident) -> __U) -> #generate_ident<__U> {
+                    #generate_ident { #(#mapped,)* #(#hidden_inits,)* }
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    if let Some((field, _)) = members.iter().find(|(_, attrs)| attrs.id && attrs.group.is_some()) {
+        return syn::Error::new(field.span(), "`id` cannot be combined with `group`.").to_compile_error().into()
+    }
+    let ids = fields.iter().filter(|(_, attrs)| attrs.id).collect::<Vec<_>>();
+    if let (Some((field, _)), false) = (fields.iter().find(|(_, attrs)| attrs.eq_skip), ids.is_empty()) {
+        return syn::Error::new(field.span(), "`eq_skip` cannot be combined with `id`, which already compares only the `id` fields.").to_compile_error().into()
+    }
+    let partial_eq = fields.iter().any(|(_, attrs)| attrs.eq_skip).then(|| {
+        let compared = fields.iter().filter(|(_, attrs)| !attrs.eq_skip).collect::<Vec<_>>();
+        let bounded = with_predicates(&container, generics, compared.iter().map(|(field, attrs)| {
+            let ty = destruct_type(field, attrs);
+            parse_quote! { #ty: ::core::cmp::PartialEq }
+        }));
+        let (eq_impl_generics, _, eq_where_clause) = bounded.split_for_impl();
+        let comparisons = compared.iter().map(|(field, attrs)| {
+            let dest = destruct_ident(field, attrs);
+            quote! { self.#dest == other.#dest }
+        });
+        quote! {
+            #[automatically_derived]
+            impl #eq_impl_generics ::core::cmp::PartialEq for #generate_ident #ty_generics #eq_where_clause {
+                fn eq(&self, other: &Self) -> bool {
+                    true #(&& #comparisons)*
+                }
+            }
+        }
+    });
+
+    if let Some((field, _)) = members.iter().find(|(_, attrs)| attrs.sort_key && attrs.group.is_some()) {
+        return syn::Error::new(field.span(), "`sort_key` cannot be combined with `group`.").to_compile_error().into()
+    }
+    let sort_keys = fields.iter().filter(|(_, attrs)| attrs.sort_key).collect::<Vec<_>>();
+    if let Some((field, _)) = sort_keys.get(1) {
+        return syn::Error::new(field.span(), "`sort_key` can only be given to one field.").to_compile_error().into()
+    }
+    if let (Some((field, _)), false) = (fields.iter().find(|(_, attrs)| attrs.eq_skip || attrs.id), sort_keys.is_empty()) {
+        return syn::Error::new(field.span(), "`eq_skip` and `id` cannot be combined with `sort_key`, which already compares only the `sort_key` field.").to_compile_error().into()
+    }
+    let sort_key = sort_keys.first().map(|(field, attrs)| {
+        let ty = destruct_type(field, attrs);
+        let bound = |bound: Path| with_predicates(&container, generics, [parse_quote! { #ty: #bound }]);
+        let eq_bounded = bound(parse_quote! { ::core::cmp::PartialEq });
+        let (eq_impl_generics, _, eq_where_clause) = eq_bounded.split_for_impl();
+        let total_bounded = bound(parse_quote! { ::core::cmp::Eq });
+        let (total_impl_generics, _, total_where_clause) = total_bounded.split_for_impl();
+        let partial_bounded = bound(parse_quote! { ::core::cmp::PartialOrd });
+        let (partial_impl_generics, _, partial_where_clause) = partial_bounded.split_for_impl();
+        let ord_bounded = bound(parse_quote! { ::core::cmp::Ord });
+        let (ord_impl_generics, _, ord_where_clause) = ord_bounded.split_for_impl();
+        let dest = destruct_ident(field, attrs);
+        quote! {
+            #[automatically_derived]
+            impl #eq_impl_generics ::core::cmp::PartialEq for #generate_ident #ty_generics #eq_where_clause {
+                fn eq(&self, other: &Self) -> bool {
+                    self.#dest == other.#dest
+                }
+            }
+
+            #[automatically_derived]
+            impl #total_impl_generics ::core::cmp::Eq for #generate_ident #ty_generics #total_where_clause {}
+
+            #[automatically_derived]
+            impl #partial_impl_generics ::core::cmp::PartialOrd for #generate_ident #ty_generics #partial_where_clause {
+                fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                    ::core::cmp::PartialOrd::partial_cmp(&self.#dest, &other.#dest)
+                }
+            }
+
+            #[automatically_derived]
+            impl #ord_impl_generics ::core::cmp::Ord for #generate_ident #ty_generics #ord_where_clause {
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    ::core::cmp::Ord::cmp(&self.#dest, &other.#dest)
+                }
+            }
+        }
+    });
+
+    let identity = (!ids.is_empty()).then(|| {
+        let bound = |bound: Path| with_predicates(&container, generics, ids.iter().map(|(field, attrs)| {
+            let ty = destruct_type(field, attrs);
+            parse_quote! { #ty: #bound }
+        }));
+        let eq_bounded = bound(parse_quote! { ::core::cmp::PartialEq });
+        let (eq_impl_generics, _, eq_where_clause) = eq_bounded.split_for_impl();
+        let total_bounded = bound(parse_quote! { ::core::cmp::Eq });
+        let (total_impl_generics, _, total_where_clause) = total_bounded.split_for_impl();
+        let hash_bounded = bound(parse_quote! { ::core::hash::Hash });
+        let (hash_impl_generics, _, hash_where_clause) = hash_bounded.split_for_impl();
+        let dests = ids.iter().map(|(field, attrs)| destruct_ident(field, attrs)).collect::<Vec<_>>();
+        quote! {
+            #[automatically_derived]
+            impl #eq_impl_generics ::core::cmp::PartialEq for #generate_ident #ty_generics #eq_where_clause {
+                fn eq(&self, other: &Self) -> bool {
+                    true #(&& self.#dests == other.#dests)*
+                }
+            }
+
+            #[automatically_derived]
+            impl #total_impl_generics ::core::cmp::Eq for #generate_ident #ty_generics #total_where_clause {}
+
+            #[automatically_derived]
+            impl #hash_impl_generics ::core::hash::Hash for #generate_ident #ty_generics #hash_where_clause {
+                fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                    #(::core::hash::Hash::hash(&self.#dests, state);)*
+                }
+            }
+        }
+    });
+
+    let borrow_view = match container.borrow_view {
+        false => None,
+        true if is_packed(&ast.attrs) => return quote_spanned! { name.span() => compile_error!("`borrow_view` cannot be used on `#[repr(packed)]` structures, since references to their fields may be misaligned."); }.into(),
+        true if !groups.is_empty() => return quote_spanned! { name.span() => compile_error!("`borrow_view` cannot be combined with `group`, since `Destruct{Name}Ref` has no sub-structures."); }.into(),
+        true => Some(ref_view(&ast, &container, &members, "borrow_destruct")),
+    };
+
+    let q = quote::quote! {
+        /// Do not have an explicit implementation for this structure.
+        #doc_hidden
+        #serde_derive
+        pub struct #generate_ident #generics #where_clause {
+            #(#destruction,)*
+        }
+
+        #(#group_structs)*
+
+        #[automatically_derived]
+        #doc_hidden
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Convert the field value to a fully disclosed Destruct structure.
+            /// 
+            /// If you wish to revert the Destruct structure back to the original structure, see `freeze()`.
+            #[inline]
+            pub fn into_destruct(self) -> #generate_ident #ty_generics {
+                #generate_ident { #(#expanded,)* }
+            }
+
+            #reconstruct
+
+            #try_reconstruct
+
+            #try_reconstruct_ctx
+
+            #try_reconstruct_all
+
+            #replace_with
+
+            #(#swaps)*
+
+            #editor
+
+            #reconstruct_tracked
+
+            #to_destruct
+
+            #into_destruct_boxed
+
+            #(#cow_accessors)*
+
+            #map_generic_field
+        }
+
+        #test_fixture
+
+        #helpers
+
+        #editor_struct
+
+        #[automatically_derived]
+        impl #impl_generics #generate_ident #ty_generics #where_clause {
+            #freeze
+
+            #try_freeze_all
+
+            #freeze_boxed
+
+            #edit
+
+            #(#getters)*
+
+            #(#collection_methods)*
+
+            #(#takes)*
+
+            #as_tuple
+
+            #as_refs
+
+            #into_string_map
+
+            #(#unsafe_setters)*
+        }
+
+        #parse_error
+
+        #reconstruct_error
+
+        #build_error
+
+        #from_str
+
+        #from_string_map
+
+        #into_iter
+
+        #fold
+
+        #index_usize
+
+        #visible_fields
+
+        #validate_all
+
+        #into_tuple
+
+        #as_mut_view
+
+        #debug
+
+        #clone
+
+        #display
+
+        #field_names
+
+        #size_hint
+
+        #checksum
+
+        #reflect
+
+        #field_enum
+
+        #schema
+
+        #patch
+
+        #with_defaults
+
+        #diff_patch
+
+        #default_fields
+
+        #arbitrary
+
+        #from
+
+        #staged
+
+        #(#adapts)*
+
+        #map_all
+
+        #partial_eq
+
+        #identity
+
+        #sort_key
+
+        #borrow_view
+
+        #json
+    };
+
+    q.into()
+}
+
+
+/// `#[derive(Destructure)]` with `#[destructure(transparent)]`.
+fn derive_transparent(ast: &DeriveInput, container: &ContainerAttributes) -> TokenStream {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let fields = if let Data::Struct(DataStruct { ref fields, .. }) = ast.data {
+        fields
+    } else {
+        return quote_spanned! { name.span() => compile_error!("Only structures are supported."); }.into()
+    };
+
+    let parsed = match fields.iter()
+        .map(|field| FieldAttributes::parse(&field.attrs).map(|attrs| (field, attrs)))
+        .collect::<syn::Result<Vec<_>>>() {
+        Ok(parsed) => parsed,
+        Err(e) => return e.to_compile_error().into()
+    };
+
+    let mut exposed = parsed.iter().enumerate().filter(|(_, (_, attrs))| !attrs.skip);
+    let (index, (field, _)) = match (exposed.next(), exposed.next()) {
+        (Some(field), None) => field,
+        _ => return quote_spanned! { name.span() => compile_error!("`transparent` requires exactly one non-skipped field."); }.into()
+    };
+    let ty = &field.ty;
+    let member = match &field.ident {
+        Some(ident) => quote! { #ident },
+        None => {
+            let index = syn::Index::from(index);
+            quote! { #index }
+        }
+    };
+
+    let inits = parsed.iter().enumerate().map(|(i, (field, _))| {
+        let value = if i == index {
+            quote! { value }
+        } else {
+            quote! { ::core::default::Default::default() }
+        };
+        match &field.ident {
+            Some(ident) => quote! { #ident: #value },
+            None => {
+                let i = syn::Index::from(i);
+                quote! { #i: #value }
+            }
+        }
+    });
+
+    let reconstruct = container.generates("reconstruct").then(|| quote! {
+        /// Replaces the inner value using a limited closure.
+        ///
+        /// Unlike `from_destruct()`, the skipped fields keep their values.
+        #[track_caller]
+        pub fn reconstruct(mut self, f: impl FnOnce(&mut #ty)) -> Self {
+            f(&mut self.#member);
+            self
+        }
+    });
+
+    let try_reconstruct = container.generates("try_reconstruct").then(|| quote! {
+        #[track_caller]
+        pub fn try_reconstruct<E>(mut self, f: impl FnOnce(&mut #ty) -> Result<(), E>) -> Result<Self, E> {
+            f(&mut self.#member)?;
+            Ok(self)
+        }
+    });
+
+    let q = quote::quote! {
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Unwrap the inner value.
+            ///
+            /// If you wish to wrap the value back to the original structure, see `from_destruct()`.
+            #[inline]
+            pub fn into_destruct(self) -> #ty {
+                self.#member
+            }
+
+            /// Wrap the value into the original structure again.
+            #[inline]
+            pub fn from_destruct(value: #ty) -> Self {
+                Self { #(#inits,)* }
+            }
+
+            #reconstruct
+
+            #try_reconstruct
+        }
+    };
+
+    q.into()
+}
+
+/// Automatically implements `as_destruct()` method.
+///
+/// Unlike [`Destructure`], the generated structure holds the references of the field values,
+/// so the original structure can be decomposed without being consumed.
+///
+/// The field attributes `#[destructure(rename = "...")]` and `#[destructure(skip)]`
+/// are shared with [`Destructure`], so both generated structures expose the same fields.
+///
+/// With `#[destructure(merge_from_ref)]`, `merge_from_ref(&mut self, view)` is also generated,
+/// which overwrites the fields with clones of the values borrowed by another `Destruct{Name}Ref`.
+/// Every field type must implement `Clone`.
+///
+/// A field given `#[destructure(copy)]` is held by value instead of by reference, copied out of the original structure,
+/// which saves dereferencing small `Copy` fields such as `u64`. The field type must implement `Copy`.
+///
+/// With `#[destructure(from)]`, `From<&'a Book>` is implemented for `DestructBookRef<'a>`,
+/// so that the view can be obtained by `.into()` in generic borrow contexts.
+///
+/// With `#[destructure(lifetime = "a")]`, the generated structure borrows the fields for `'a`
+/// instead of a hygienic lifetime, so it can be named as `DestructBookRef<'a>`.
+/// The lifetime must not be declared on the original structure.
+///
+/// `#[deprecated]` and `#[cfg(...)]` on a field are forwarded to the generated field, as with [`Destructure`].
+///
+/// `#[repr(packed)]` structures are rejected, as references to their fields may be misaligned.
+///
+/// The container options other than `merge_from_ref`, `from`, `lifetime`, `doc_hidden`, `respect_serde_skip`
+/// and `bound_relaxed` are rejected, since the `#[destructure(...)]` attribute is shared with [`Destructure`]
+/// and they would otherwise be ignored. To combine them with a borrowed view, derive [`Destructure`]
+/// with `#[destructure(borrow_view)]` instead of both derives.
+/// ## Usage
+/// ```rust
+/// use destructure::DestructureRef;
+///
+/// #[derive(DestructureRef)]
+/// pub struct Book {
+///     id: String,
+///     name: String,
+/// }
+///
+/// # fn main() {
+/// # let book = Book { id: "123456789-abc".to_string(), name: "name".to_string() };
+/// let des: DestructBookRef = book.as_destruct();
+///
+/// println!("{}", des.name);
+/// # }
+/// ```
 //noinspection DuplicatedCode
-#[proc_macro_derive(Destructure)]
-pub fn derive_destructure(input: TokenStream) -> TokenStream {
-    let ast = parse_macro_input!(input as DeriveInput);
+#[proc_macro_derive(DestructureRef, attributes(destructure))]
+pub fn derive_destructure_ref(input: TokenStream) -> TokenStream {
+    let mut ast = parse_macro_input!(input as DeriveInput);
+    replace_self(&mut ast);
     let name = &ast.ident;
-    let generics = &ast.generics;
 
-    let generate = format!("Destruct{}", name);
-    let generate_ident = Ident::new(&generate, name.span());
+    let container = match ContainerAttributes::parse(&ast.attrs) {
+        Ok(container) => container,
+        Err(e) => return e.to_compile_error().into()
+    };
+
+    if let Some(option) = container.unused(&["lifetime", "merge_from_ref", "from", "doc_hidden", "respect_serde_skip", "bound_relaxed"]) {
+        return syn::Error::new(
+            option.span(),
+            format!("`{}` is not used by `DestructureRef`. To share options with `Destructure`, derive it with `borrow_view` instead.", option)
+        ).to_compile_error().into()
+    }
+
+    if is_packed(&ast.attrs) {
+        return quote_spanned! { name.span() => compile_error!("`DestructureRef` cannot be derived for `#[repr(packed)]` structures, since references to their fields may be misaligned. Use `Destructure`, which moves the fields by value, instead."); }.into()
+    }
 
     let fields = if let Data::Struct(DataStruct { fields: Fields::Named(FieldsNamed { ref named, ..}), .. }) = ast.data {
         named
     } else {
-        return quote_spanned! { name.span() => compile_error!("Only structures with named fields are supported.") }.into()
+        return quote_spanned! { name.span() => compile_error!("Only structures with named fields are supported."); }.into()
     };
 
-    let destruction = fields.iter().map(|field| {
-        let name = &field.ident;
+    let fields = match parse_fields(&container, fields) {
+        Ok(fields) => fields,
+        Err(e) => return e.to_compile_error().into()
+    };
+
+    ref_view(&ast, &container, &fields, "as_destruct").into_token_stream().into()
+}
+
+/// Generates the `Destruct{Name}Ref` structure holding the references of the field values,
+/// and the method `method` of the original structure which borrows it.
+fn ref_view(ast: &DeriveInput, container: &ContainerAttributes, fields: &[(&Field, FieldAttributes)], method: &str) -> impl ToTokens {
+    let name = &ast.ident;
+    let generics = &ast.generics;
+    let generate_ident = Ident::new(&format!("Destruct{}Ref", name), name.span());
+    let method = Ident::new(method, name.span());
+
+    let lifetime = match &container.lifetime {
+        Some(lifetime) if generics.lifetimes().any(|param| param.lifetime.ident == lifetime.ident) => {
+            return syn::Error::new(
+                lifetime.span(),
+                format!("lifetime `{}` is already declared on `{}`.", lifetime, name)
+            ).to_compile_error()
+        },
+        Some(lifetime) => lifetime.clone(),
+        None => fresh_lifetime(generics, "'__origin_destruct_lifetime")
+    };
+    let generics_with_lt = with_lifetime(generics, &lifetime);
+    let generics_with_anonymous = with_lifetime(generics, &Lifetime::new("'_", generics.span()));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (_, ty_generics_with_anonymous, _) = generics_with_anonymous.split_for_impl();
+
+    let fields = fields.iter().filter(|(_, attrs)| !attrs.skip_drop).collect::<Vec<_>>();
+
+    let merge_from_ref = container.merge_from_ref.then(|| {
+        let bounded = with_predicates(container, generics, fields.iter().map(|(field, _)| {
+            let ty = &field.ty;
+            parse_quote! { #ty: ::core::clone::Clone }
+        }));
+        let (_, _, merge_where_clause) = bounded.split_for_impl();
+        let assigns = fields.iter().map(|(field, attrs)| {
+            let name = &field.ident;
+            let dest = destruct_ident(field, attrs);
+            quote! {
+                self.#name = ::core::clone::Clone::clone(&view.#dest);
+            }
+        });
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #name #ty_generics #merge_where_clause {
+                /// Overwrites the fields with clones of the values borrowed by `view`.
+                pub fn merge_from_ref(&mut self, view: &#generate_ident #ty_generics_with_anonymous) {
+                    #(#assigns)*
+                }
+            }
+        }
+    });
+
+    let destruction = fields.iter().map(|(field, attrs)| {
+        let name = destruct_ident(field, attrs);
         let ty = &field.ty;
+        let vis = (!attrs.skip).then(|| quote! { pub });
+        let forwarded = field.attrs.iter().filter(|attr| attr.path().is_ident("deprecated") || attr.path().is_ident("cfg"));
+        let reference = (!attrs.copy).then(|| quote! { &#lifetime });
+        quote! {
+            #(#forwarded)*
+            #vis #name: #reference #ty
+        }
+    }).collect::<Vec<_>>();
+    // The lifetime would be unused if every field is copied.
+    let (phantom_field, phantom_init) = match fields.iter().all(|(_, attrs)| attrs.copy) {
+        true => (
+            Some(quote! { __destruct_phantom: ::core::marker::PhantomData<&#lifetime ()>, }),
+            Some(quote! { __destruct_phantom: ::core::marker::PhantomData, })
+        ),
+        false => (None, None)
+    };
+
+    let from = container.from.then(|| {
+        let (impl_generics_with_lt, ty_generics_with_lt, _) = generics_with_lt.split_for_impl();
         quote! {
-            pub #name: #ty
+            #[automatically_derived]
+            impl #impl_generics_with_lt ::core::convert::From<&#lifetime #name #ty_generics> for #generate_ident #ty_generics_with_lt #where_clause {
+                fn from(origin: &#lifetime #name #ty_generics) -> Self {
+                    origin.#method()
+                }
+            }
         }
     });
 
-    let expanded = fields.iter().map(|field| {
+    let expanded = fields.iter().map(|(field, attrs)| {
         let name = &field.ident;
+        let dest = destruct_ident(field, attrs);
+        let cfgs = cfg_attrs(field);
+        let reference = (!attrs.copy).then(|| quote! { & });
         quote! {
-            #name: self.#name
+            #(#cfgs)* #dest: #reference self.#name
         }
     });
 
-    let freeze = expanded.clone();
-
-    let q = quote::quote! {
+    let doc_hidden = container.doc_hidden.then(|| quote! { #[doc(hidden)] });
+    quote! {
         /// Do not have an explicit implementation for this structure.
-        pub struct #generate_ident #generics {
+        #doc_hidden
+        pub struct #generate_ident #generics_with_lt #where_clause {
             #(#destruction,)*
+            #phantom_field
         }
 
-        impl #generics #name #generics {
-            /// Convert the field value to a fully disclosed Destruct structure.
-            /// 
-            /// If you wish to revert the Destruct structure back to the original structure, see `freeze()`.
-            pub fn into_destruct(self) -> #generate_ident #generics {
-                #generate_ident { #(#expanded,)* }
+        #[automatically_derived]
+        #doc_hidden
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Decompose the field values into a fully disclosed Destruct structure by reference.
+            pub fn #method(&self) -> #generate_ident #ty_generics_with_anonymous {
+                #generate_ident { #(#expanded,)* #phantom_init }
             }
+        }
 
-            /// It provides a mechanism for replacing the contents by [`into_destruct()`]
-            /// and changing the actual value by [`freeze()`] using a limited closure.
-            ///
-            /// If you wish to use Result, see [`try_reconstruct()`].
-            pub fn reconstruct(self, f: impl FnOnce(&mut #generate_ident #generics)) -> Self {
-                let mut dest = self.into_destruct();
-                f(&mut dest);
-                dest.freeze()
+        #merge_from_ref
+
+        #from
+    }
+}
+
+/// Parses the field attributes of each field, in the order of the fields in the generated structure.
+///
+/// Fields given `order` come first in ascending order, followed by the others in declaration order.
+/// With `respect_serde_skip`, the fields skipped by serde are marked as skipped here, so that both derives agree.
+/// Fails if several fields end up with the same name in the generated structure (e.g. by `rename`),
+/// or with the same `order`.
+fn parse_fields<'a>(container: &ContainerAttributes, fields: impl IntoIterator<Item = &'a Field>) -> syn::Result<Vec<(&'a Field, FieldAttributes)>> {
+    let mut fields = fields.into_iter()
+        .map(|field| FieldAttributes::parse(&field.attrs).map(|attrs| (field, attrs)))
+        .collect::<syn::Result<Vec<_>>>()?;
+    if container.respect_serde_skip {
+        for (_, attrs) in fields.iter_mut().filter(|(field, attrs)| !attrs.skip_drop && is_serde_skipped(field)) {
+            attrs.skip = true;
+        }
+    }
+
+    let order = |attrs: &FieldAttributes| attrs.order.as_ref().map(|lit| lit.base10_parse::<usize>().unwrap());
+    for (i, (_, attrs)) in fields.iter().enumerate() {
+        let Some(lit) = &attrs.order else { continue };
+        if fields[..i].iter().any(|(_, other)| order(other) == order(attrs)) {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!("order `{}` is used more than once.", lit)
+            ))
+        }
+    }
+    fields.sort_by_key(|(_, attrs)| order(attrs).map_or((1, 0), |order| (0, order)));
+
+    for (i, (field, attrs)) in fields.iter().enumerate() {
+        let dest = destruct_ident(field, attrs).unraw();
+        let duplicated = fields[..i].iter()
+            .any(|(other, attrs)| destruct_ident(other, attrs).unraw() == dest);
+        if duplicated {
+            return Err(syn::Error::new(
+                dest.span(),
+                format!("field name `{}` is used more than once in the generated structure.", dest)
+            ))
+        }
+    }
+
+    for (field, attrs) in fields.iter_mut().filter(|(_, attrs)| attrs.zeroize) {
+        let ty = destruct_type(field, attrs);
+        attrs.zeroized = Some(parse_quote! { ::zeroize::Zeroizing<#ty> });
+    }
+
+    Ok(fields)
+}
+
+/// Returns the only generic parameter, if it is a type parameter which every field has as its type.
+fn single_type_param<'a>(generics: &'a Generics, mut types: impl Iterator<Item = &'a Type>) -> Option<&'a TypeParam> {
+    if generics.params.len() != 1 || generics.where_clause.is_some() {
+        return None
+    }
+    let param = generics.type_params().next()?;
+    let is_param = |ty: &Type| matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident(&param.ident));
+    types.all(is_param).then_some(param)
+}
+
+/// `#[cfg(...)]` attributes of the field, forwarded wherever the field is declared or initialized.
+fn cfg_attrs(field: &Field) -> impl Iterator<Item = &Attribute> {
+    field.attrs.iter().filter(|attr| attr.path().is_ident("cfg"))
+}
+
+/// Whether the structure is declared with `#[repr(packed)]` or `#[repr(packed(N))]`.
+fn is_packed(attrs: &[Attribute]) -> bool {
+    attrs.iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+        .filter_map(|attr| attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated).ok())
+        .any(|reprs| reprs.iter().any(|repr| repr.path().is_ident("packed")))
+}
+
+/// Whether the field is left out of serialization by `#[serde(skip)]` or `#[serde(skip_serializing)]`.
+fn is_serde_skipped(field: &Field) -> bool {
+    field.attrs.iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated).ok())
+        .any(|metas| metas.iter().any(|meta| meta.path().is_ident("skip") || meta.path().is_ident("skip_serializing")))
+}
+
+/// Returns a lifetime named after `base` which does not collide with the ones declared on the original structure.
+fn fresh_lifetime(generics: &Generics, base: &str) -> Lifetime {
+    let mut name = base.to_string();
+    while generics.lifetimes().any(|param| param.lifetime.ident == name[1..]) {
+        name.push('_');
+    }
+    Lifetime::new(&name, generics.span())
+}
+
+/// Returns the name of the collection (`Vec`, `HashSet` or `HashMap`) and its type arguments, detected by the last path segment.
+fn collection_kind(ty: &Type) -> Option<(&'static str, Vec<&Type>)> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    let kind = ["Vec", "HashSet", "HashMap"].into_iter().find(|kind| segment.ident == kind)?;
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else { return None };
+    let types = arguments.args.iter()
+        .filter_map(|argument| match argument {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None
+        })
+        .collect();
+    Some((kind, types))
+}
+
+/// Returns `T` of an `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None
+    }
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else { return None };
+    arguments.args.iter().find_map(|argument| match argument {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None
+    })
+}
+
+/// Returns the type pointed to by an `Arc<T>` or `Rc<T>`, which `deep_clone` clones into a new pointer.
+fn shared_pointee(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Arc" && segment.ident != "Rc" {
+        return None
+    }
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else { return None };
+    arguments.args.iter().find_map(|argument| match argument {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None
+    })
+}
+
+/// Finds the field named `key` in the Destruct structure, referenced by the string of `option`.
+///
+/// Fails naming the field if it does not exist or is skipped, so that renaming or removing a field
+/// does not silently break the options referring to it.
+fn referenced_field<'a>(fields: &[&'a (&'a Field, FieldAttributes)], key: &str, at: impl ToTokens, option: &str, generate: &str) -> syn::Result<&'a (&'a Field, FieldAttributes)> {
+    match fields.iter().find(|(field, attrs)| destruct_key(field, attrs) == key) {
+        Some((_, attrs)) if attrs.skip => Err(syn::Error::new_spanned(
+            at,
+            format!("`{}` is skipped and cannot be used in `{}`.", key, option)
+        )),
+        Some(found) => Ok(*found),
+        None => Err(syn::Error::new_spanned(at, format!("`{}` is not a field of `{}`.", key, generate))),
+    }
+}
+
+/// Returns a `PhantomData` marking the generic parameters which none of `types` refers to, if any.
+///
+/// Lifetimes are marked as `&'a ()` and type parameters as `fn() -> PhantomData<T>`,
+/// so that the marker neither requires `T: Sized` nor affects auto traits such as `Send`.
+fn phantom_type<'a>(generics: &Generics, types: impl Iterator<Item = &'a Type>) -> Option<Type> {
+    let mentions = mentioned_idents(types);
+
+    let markers = generics.params.iter().filter_map(|param| -> Option<Type> {
+        match param {
+            GenericParam::Lifetime(LifetimeParam { lifetime, .. }) if !mentions.contains(&lifetime.ident) => {
+                Some(parse_quote! { &#lifetime () })
+            },
+            GenericParam::Type(TypeParam { ident, .. }) if !mentions.contains(ident) => {
+                Some(parse_quote! { fn() -> ::core::marker::PhantomData<#ident> })
+            },
+            _ => None
+        }
+    }).collect::<Vec<_>>();
+
+    (!markers.is_empty()).then(|| parse_quote! { ::core::marker::PhantomData<(#(#markers,)*)> })
+}
+
+/// Replaces `Self` in the field types with the original structure,
+/// since the generated structures would otherwise refer to themselves.
+fn replace_self(ast: &mut DeriveInput) {
+    struct ReplaceSelf(Type);
+
+    impl syn::visit_mut::VisitMut for ReplaceSelf {
+        fn visit_type_mut(&mut self, ty: &mut Type) {
+            match ty {
+                Type::Path(path) if path.qself.is_none() && path.path.is_ident("Self") => *ty = self.0.clone(),
+                _ => syn::visit_mut::visit_type_mut(self, ty)
             }
+        }
+    }
 
-            pub fn try_reconstruct<E>(self, f: impl FnOnce(&mut #generate_ident #generics) -> Result<(), E>) -> Result<Self, E> {
-                let mut dest = self.into_destruct();
-                f(&mut dest)?;
-                Ok(dest.freeze())
+    let name = &ast.ident;
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let mut replace = ReplaceSelf(parse_quote! { #name #ty_generics });
+    if let Data::Struct(data) = &mut ast.data {
+        data.fields.iter_mut().for_each(|field| syn::visit_mut::VisitMut::visit_type_mut(&mut replace, &mut field.ty));
+    }
+}
+
+/// Returns every identifier appearing in `types`, including the names of lifetimes.
+fn mentioned_idents<'a>(types: impl Iterator<Item = &'a Type>) -> Vec<Ident> {
+    #[derive(Default)]
+    struct Mentions(Vec<Ident>);
+
+    impl<'ast> syn::visit::Visit<'ast> for Mentions {
+        fn visit_ident(&mut self, ident: &'ast Ident) {
+            self.0.push(ident.clone());
+        }
+    }
+
+    let mut mentions = Mentions::default();
+    types.for_each(|ty| syn::visit::Visit::visit_type(&mut mentions, ty));
+    mentions.0
+}
+
+/// Fields gathered into a sub-structure of the Destruct structure by `#[destructure(group = "...")]`.
+struct Group<'a> {
+    /// Name of the field holding the sub-structure.
+    ident: Ident,
+    /// Name of the sub-structure, e.g. `DestructBookMeta` for the group `meta` of `Book`.
+    struct_ident: Ident,
+    /// Grouped fields in declaration order.
+    members: Vec<&'a (&'a Field, FieldAttributes)>,
+    /// Whether the sub-structure is flattened by `#[serde(flatten)]`, if any member is given `serde`.
+    flatten: bool,
+}
+
+/// Collects the groups in order of their first appearance.
+fn collect_groups<'a>(ast: &DeriveInput, generate: &str, members: &'a [(&'a Field, FieldAttributes)]) -> syn::Result<Vec<Group<'a>>> {
+    let mut groups: Vec<Group> = Vec::new();
+    for member in members {
+        let Some(group) = &member.1.group else {
+            if member.1.serde {
+                return Err(syn::Error::new(member.0.span(), "`serde` can only be used with `group`."))
             }
+            continue
+        };
+        if !ast.generics.params.is_empty() {
+            return Err(syn::Error::new(group.span(), "`group` is not supported on generic structures."))
+        }
+        let collides = members.iter()
+            .filter(|(_, attrs)| attrs.group.is_none())
+            .any(|(field, attrs)| destruct_ident(field, attrs).unraw() == group.unraw());
+        if collides {
+            return Err(syn::Error::new(group.span(), format!("group `{}` collides with a field of the same name.", group)))
+        }
+        match groups.iter_mut().find(|g| g.ident == *group) {
+            Some(g) => {
+                g.members.push(member);
+                g.flatten |= member.1.serde;
+            },
+            None => groups.push(Group {
+                ident: group.clone(),
+                struct_ident: Ident::new(&format!("{}{}", generate, to_pascal_case(&group.unraw().to_string())), group.span()),
+                members: vec![member],
+                flatten: member.1.serde,
+            })
         }
+    }
+    Ok(groups)
+}
 
-        impl #generics #generate_ident #generics {
-            /// Restore the Destruct structure to its original structure again.
-            pub fn freeze(self) -> #name #generics {
-                #name { #(#freeze,)* }
+/// Converts a `PascalCase` name into `snake_case`.
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i != 0 {
+                snake.push('_');
             }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
         }
-    };
+    }
+    snake
+}
 
-    q.into()
+/// Converts a `snake_case` name into `PascalCase`.
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Prepends `lifetime` to the generic parameters, since lifetimes must precede the others.
+fn with_lifetime(generics: &Generics, lifetime: &Lifetime) -> Generics {
+    let mut generics = generics.clone();
+    generics.params.insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+    generics
+}
+
+/// Appends `predicates` required by a generated item to the where clause,
+/// keeping the bounds declared on the original structure.
+///
+/// With `#[destructure(bound_relaxed)]`, nothing is appended and the declared bounds are used as is.
+fn with_predicates(container: &ContainerAttributes, generics: &Generics, predicates: impl IntoIterator<Item = WherePredicate>) -> Generics {
+    let mut generics = generics.clone();
+    if !container.bound_relaxed {
+        generics.make_where_clause().predicates.extend(predicates);
+    }
+    generics
+}
+
+/// Returns the distinct field names referred to by the `{name}` placeholders of a format template,
+/// along with the template whose placeholders are renamed to `{__name}`, so that they can be given
+/// as named arguments even if the field is named after a keyword (e.g. `r#type`).
+///
+/// Escaped braces (`{{` and `}}`) are kept as is and format specs (e.g. `{name:>8}`) are allowed,
+/// but positional placeholders are rejected since there are no positional arguments to refer to.
+fn template_placeholders(template: &LitStr) -> syn::Result<(String, Vec<String>)> {
+    let value = template.value();
+    let mut rewritten = String::with_capacity(value.len());
+    let mut placeholders: Vec<String> = Vec::new();
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                rewritten.push_str("{{");
+            },
+            '{' => {
+                let inner = chars.by_ref().take_while(|c| *c != '}').collect::<String>();
+                let (name, spec) = inner.split_at(inner.find(':').unwrap_or(inner.len()));
+                let name = name.trim();
+                if Ident::parse_any.parse_str(name).is_err() {
+                    return Err(syn::Error::new(
+                        template.span(),
+                        format!("`{{{}}}` must name a field of the generated structure.", inner)
+                    ))
+                }
+                rewritten.push_str(&format!("{{__{}{}}}", name, spec));
+                if !placeholders.iter().any(|placeholder| placeholder == name) {
+                    placeholders.push(name.to_string());
+                }
+            },
+            _ => rewritten.push(c)
+        }
+    }
+    Ok((rewritten, placeholders))
 }
 
+/// Returns the type shared by all fields, which is required by the `option` attribute.
+fn homogeneous_type<'a>(mut types: impl Iterator<Item = &'a Type>, option: &str, name: &Ident) -> syn::Result<&'a Type> {
+    let first = types.next().ok_or_else(|| syn::Error::new(
+        name.span(),
+        format!("`{}` requires at least one field.", option)
+    ))?;
+    match types.find(|ty| *ty != first) {
+        Some(ty) => Err(syn::Error::new(
+            ty.span(),
+            format!("`{}` requires all fields to have the same type.", option)
+        )),
+        None => Ok(first)
+    }
+}
 
 /// Automatically implements `substitute()` methods.
 ///
@@ -263,64 +3431,338 @@ pub fn derive_destructure(input: TokenStream) -> TokenStream {
 ///    Ok(())
 /// }).expect("Error");
 /// # }
+/// ```
+///
+/// `#[cfg(...)]` on a field is forwarded to the generated field and its initialization.
+///
+/// ## Attributes
+/// - `#[mutation(async)]`: Generates `async_try_substitute(&mut self, f)`, whose closure returns a future
+///   resolving to `Result<(), E>`. Since the future has to hold the mutable references while awaiting,
+///   the closure takes `BookMut` by value, borrowing `self` until the future completes.
+///   The returned future is `Send` only if the future returned by the closure is,
+///   which in turn requires the field types to be `Send` when they are used across an `.await`.
+/// - `#[mutation(array)]`: Generates `fields_mut(&mut self) -> [&mut T; N]` borrowing every field
+///   in the order of the declaration. All fields must have the same type, and it cannot be combined with `#[cfg]` fields.
+/// - `#[mutation(pin)]`: Generates `substitute_pinned(self: Pin<&mut Self>, f)`, whose closure takes `BookPinMut`
+///   holding the fields marked with `#[mutation(pinned)]` as `Pin<&mut T>` and the others as `&mut T`,
+///   for `!Unpin` structures such as hand-written futures. Only the fields marked as `pinned` are structurally pinned;
+///   the others may be moved freely, e.g. by `std::mem::swap`, so they must not rely on being pinned.
+///   To keep the projection sound, the structure is `Unpin` only if its pinned fields are, and it must not implement `Drop`.
+///   Not supported on generic structures, and it cannot be combined with `on_change`.
+///
+/// ## Field Attributes
+/// - `#[mutation(on_change = "path::to::callback")]`: Calls `callback(&new_value)` after the substitution
+///   if the value of the field has changed. The field is snapshotted before the closure runs,
+///   so its type must implement `Clone` and `PartialEq`. When the closure of `try_substitute()` fails,
+///   no callback is called even though the changes made so far are kept.
+/// - `#[mutation(group = "pricing")]`: Gathers the fields marked with the same name into a partial view
+///   (e.g. `BookPricingMut`) and generates `substitute_pricing(&mut self, f)`, whose closure can only touch those fields.
+///   The callbacks of `on_change` in the group are also called. Not supported on generic structures.
+/// - `#[mutation(pinned)]`: With `#[mutation(pin)]`, projects the field as `Pin<&mut T>` in `substitute_pinned()`.
 //noinspection DuplicatedCode
-#[proc_macro_derive(Mutation)]
+#[proc_macro_derive(Mutation, attributes(mutation))]
 pub fn derive_mutation(input: TokenStream) -> TokenStream {
-    let ast = parse_macro_input!(input as DeriveInput);
+    let mut ast = parse_macro_input!(input as DeriveInput);
+    replace_self(&mut ast);
     let name = &ast.ident;
     let generics = &ast.generics;
+
+    let attributes = match MutationAttributes::parse(&ast.attrs) {
+        Ok(attributes) => attributes,
+        Err(e) => return e.to_compile_error().into()
+    };
     
+    if is_packed(&ast.attrs) {
+        return quote_spanned! { name.span() => compile_error!("`Mutation` cannot be derived for `#[repr(packed)]` structures, since references to their fields may be misaligned. Use `Destructure`, which moves the fields by value, instead."); }.into()
+    }
+
     let generate = format!("{}Mut", name);
     let generate_ident = Ident::new(&generate, name.span());
 
     let fields = if let Data::Struct(DataStruct { fields: Fields::Named(FieldsNamed { ref named, ..}), .. }) = ast.data {
         named
     } else {
-        return quote_spanned! { name.span() => compile_error!("Only structures with named fields are supported.") }.into()
+        return quote_spanned! { name.span() => compile_error!("Only structures with named fields are supported."); }.into()
     };
 
-    let lifetime = Lifetime::new("'mutation", generics.span());
-    let generics_gn = generics.params.iter();
-    let generics_with_lt = quote! {
-        <#lifetime, #(#generics_gn,)*>
-    };
+    let lifetime = fresh_lifetime(generics, "'__origin_mutation_lifetime");
+    let generics_with_lt = with_lifetime(generics, &lifetime);
+    let generics_with_anonymous = with_lifetime(generics, &Lifetime::new("'_", generics.span()));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (_, ty_generics_with_anonymous, _) = generics_with_anonymous.split_for_impl();
     
     let destruction = fields.iter().map(|field| {
         let name = &field.ident;
         let ty = &field.ty;
+        let cfgs = cfg_attrs(field);
         quote! {
-            pub #name: &'mutation mut #ty
+            #(#cfgs)* pub #name: &#lifetime mut #ty
         }
     });
 
     let expanded = fields.iter().map(|field| {
         let name = &field.ident;
+        let cfgs = cfg_attrs(field);
         quote! {
-            #name: &mut self.#name
+            #(#cfgs)* #name: &mut self.#name
         }
     });
 
     let expanded_cloned = expanded.clone();
 
+    let mut watched = Vec::new();
+    let mut groups: Vec<(Ident, Vec<&Field>)> = Vec::new();
+    let mut pinned = Vec::new();
+    for field in fields {
+        let attrs = match MutationFieldAttributes::parse(&field.attrs) {
+            Ok(attrs) => attrs,
+            Err(e) => return e.to_compile_error().into()
+        };
+        if attrs.pinned {
+            if !attributes.pin {
+                return syn::Error::new(field.span(), "`pinned` requires `#[mutation(pin)]` on the structure.").to_compile_error().into()
+            }
+            pinned.push(field);
+        }
+        if let Some(on_change) = attrs.on_change {
+            watched.push((field, on_change));
+        }
+        if let Some(group) = attrs.group {
+            if !generics.params.is_empty() {
+                return syn::Error::new(group.span(), "`group` is not supported on generic structures.").to_compile_error().into()
+            }
+            match groups.iter_mut().find(|(ident, _)| *ident == group) {
+                Some((_, members)) => members.push(field),
+                None => groups.push((group, vec![field]))
+            }
+        }
+    }
+    let watch_bounds = |watched: &[&(&Field, Path)]| watched.iter().map(|(field, _)| {
+        let ty = &field.ty;
+        quote! { #ty: ::core::clone::Clone + ::core::cmp::PartialEq }
+    }).collect::<Vec<_>>();
+    let snapshots = |watched: &[&(&Field, Path)]| watched.iter().map(|(field, _)| {
+        let name = &field.ident;
+        let snapshot = Ident::new(&format!("__before_{}", name.as_ref().unwrap().unraw()), name.span());
+        let cfgs = cfg_attrs(field);
+        quote! {
+            #(#cfgs)* let #snapshot = ::core::clone::Clone::clone(&self.#name);
+        }
+    }).collect::<Vec<_>>();
+    let notifications = |watched: &[&(&Field, Path)]| watched.iter().map(|(field, on_change)| {
+        let name = &field.ident;
+        let snapshot = Ident::new(&format!("__before_{}", name.as_ref().unwrap().unraw()), name.span());
+        let cfgs = cfg_attrs(field);
+        quote! {
+            #(#cfgs)*
+            if #snapshot != self.#name {
+                #on_change(&self.#name);
+            }
+        }
+    }).collect::<Vec<_>>();
+
+    let group_views = groups.iter().map(|(group, members)| {
+        let view_ident = Ident::new(&format!("{}{}Mut", name, to_pascal_case(&group.unraw().to_string())), group.span());
+        let method = Ident::new(&format!("substitute_{}", group.unraw()), group.span());
+        let view_fields = members.iter().map(|field| {
+            let name = &field.ident;
+            let ty = &field.ty;
+            let cfgs = cfg_attrs(field);
+            quote! {
+                #(#cfgs)* pub #name: &#lifetime mut #ty
+            }
+        });
+        let view_expanded = members.iter().map(|field| {
+            let name = &field.ident;
+            let cfgs = cfg_attrs(field);
+            quote! {
+                #(#cfgs)* #name: &mut self.#name
+            }
+        });
+        let group_watched = watched.iter().filter(|(field, _)| members.iter().any(|member| std::ptr::eq(*member, *field))).collect::<Vec<_>>();
+        let group_bounds = watch_bounds(&group_watched);
+        let group_where_clause = (!group_bounds.is_empty()).then(|| quote! { where #(#group_bounds,)* });
+        let group_snapshots = snapshots(&group_watched);
+        let group_notifications = notifications(&group_watched);
+        let view_doc = format!("Mutable view of the fields of [`{}`] in the group `{}`.", name, group);
+        quote! {
+            #[doc = #view_doc]
+            pub struct #view_ident<#lifetime> {
+                #(#view_fields,)*
+            }
+
+            #[automatically_derived]
+            impl #name {
+                /// Works the same as `substitute()`, but the closure can only touch the fields in the group.
+                pub fn #method(&mut self, f: impl FnOnce(&mut #view_ident<'_>)) #group_where_clause {
+                    #(#group_snapshots)*
+                    f(&mut #view_ident {
+                        #(#view_expanded,)*
+                    });
+                    #(#group_notifications)*
+                }
+            }
+        }
+    }).collect::<Vec<_>>();
+
+    let watched = watched.iter().collect::<Vec<_>>();
+    let watch_bounds = watch_bounds(&watched);
+    let watch_where_clause = (!watch_bounds.is_empty()).then(|| quote! { where #(#watch_bounds,)* });
+    let snapshots = snapshots(&watched);
+    let notifications = notifications(&watched);
+
+    let async_try_substitute = attributes.asynchronous.then(|| {
+        let expanded = expanded.clone();
+        let (_, ty_generics_with_lt, _) = generics_with_lt.split_for_impl();
+        quote! {
+            /// Works the same as [`try_substitute()`](Self::try_substitute), but awaits the future returned by `f`.
+            ///
+            /// `f` takes the mutable view by value so that the future can hold it across `.await`,
+            /// and `self` stays borrowed until the future completes.
+            pub async fn async_try_substitute<#lifetime, E, Fut>(&#lifetime mut self, f: impl FnOnce(#generate_ident #ty_generics_with_lt) -> Fut) -> Result<(), E>
+                where Fut: ::core::future::Future<Output = Result<(), E>>, #(#watch_bounds,)*
+            {
+                #(#snapshots)*
+                f(#generate_ident {
+                    #(#expanded,)*
+                }).await?;
+                #(#notifications)*
+                Ok(())
+            }
+        }
+    });
+
+    let pin = match attributes.pin {
+        true if !generics.params.is_empty() => return quote_spanned! { name.span() => compile_error!("`pin` is not supported on generic structures."); }.into(),
+        true if !watched.is_empty() => return quote_spanned! { name.span() => compile_error!("`pin` cannot be combined with `on_change`."); }.into(),
+        true => {
+            let view_ident = Ident::new(&format!("{}PinMut", name), name.span());
+            let is_pinned = |field: &Field| pinned.iter().any(|pinned| std::ptr::eq(*pinned, field));
+            let view_fields = fields.iter().map(|field| {
+                let name = &field.ident;
+                let ty = &field.ty;
+                let cfgs = cfg_attrs(field);
+                match is_pinned(field) {
+                    true => quote! { #(#cfgs)* pub #name: ::core::pin::Pin<&#lifetime mut #ty> },
+                    false => quote! { #(#cfgs)* pub #name: &#lifetime mut #ty }
+                }
+            });
+            let projections = fields.iter().map(|field| {
+                let name = &field.ident;
+                let cfgs = cfg_attrs(field);
+                match is_pinned(field) {
+                    // SAFETY: The field is structurally pinned, so it is never moved while `self` is pinned:
+                    // `Unpin` and `Drop` of the structure are restricted below.
+                    true => quote! { #(#cfgs)* #name: unsafe { ::core::pin::Pin::new_unchecked(&mut this.#name) } },
+                    false => quote! { #(#cfgs)* #name: &mut this.#name }
+                }
+            });
+            let pinned_fields = pinned.iter().map(|field| {
+                let name = &field.ident;
+                let ty = &field.ty;
+                let cfgs = cfg_attrs(field);
+                quote! { #(#cfgs)* #name: #ty }
+            });
+            let pinned_ident = Ident::new(&format!("__{}Pinned", name), name.span());
+            let must_not_drop = Ident::new(&format!("__{}MustNotImplDrop", name), name.span());
+            let pin_lifetime = Lifetime::new("'__pin", name.span());
+            let view_doc = format!("Mutable view of the fields of a pinned [`{}`], projecting the structurally pinned ones as `Pin<&mut T>`.", name);
+            Some(quote! {
+                #[doc = #view_doc]
+                pub struct #view_ident<#lifetime> {
+                    #(#view_fields,)*
+                }
+
+                #[automatically_derived]
+                impl #name {
+                    /// Works the same as `substitute()`, but on a pinned value,
+                    /// giving the fields marked as `pinned` as `Pin<&mut T>`, and the others as `&mut T`.
+                    pub fn substitute_pinned(self: ::core::pin::Pin<&mut Self>, f: impl FnOnce(&mut #view_ident<'_>)) {
+                        // SAFETY: Only the fields marked as `pinned` are exposed as pinned, and the others are never pinned.
+                        let this = unsafe { ::core::pin::Pin::get_unchecked_mut(self) };
+                        f(&mut #view_ident {
+                            #(#projections,)*
+                        });
+                    }
+                }
+
+                const _: () = {
+                    // The structure is `Unpin` only if its pinned fields are, since they must not move once pinned.
+                    // The lifetime keeps the bound from being trivial when a field is `!Unpin`.
+                    #[allow(dead_code)]
+                    struct #pinned_ident<#pin_lifetime> {
+                        __pin: ::core::marker::PhantomData<&#pin_lifetime ()>,
+                        #(#pinned_fields,)*
+                    }
+
+                    #[automatically_derived]
+                    impl<#pin_lifetime> ::core::marker::Unpin for #name where #pinned_ident<#pin_lifetime>: ::core::marker::Unpin {}
+
+                    // `Drop` could move a pinned field out of `&mut self`, so implementing it conflicts with this.
+                    trait #must_not_drop {}
+                    #[allow(drop_bounds)]
+                    impl<T: ::core::ops::Drop> #must_not_drop for T {}
+                    impl #must_not_drop for #name {}
+                };
+            })
+        },
+        false => None
+    };
+
+    let fields_mut = match attributes.array {
+        true => {
+            if let Some(field) = fields.iter().find(|field| cfg_attrs(field).next().is_some()) {
+                return syn::Error::new(field.span(), "`array` cannot be used with `#[cfg]` fields, since the length of the array cannot be conditional.").to_compile_error().into()
+            }
+            let ty = match homogeneous_type(fields.iter().map(|field| &field.ty), "array", name) {
+                Ok(ty) => ty,
+                Err(e) => return e.to_compile_error().into()
+            };
+            let len = fields.len();
+            let names = fields.iter().map(|field| &field.ident);
+            Some(quote! {
+                /// Borrows every field mutably as an array, in the order of the declaration.
+                pub fn fields_mut(&mut self) -> [&mut #ty; #len] {
+                    [#(&mut self.#names,)*]
+                }
+            })
+        },
+        false => None
+    };
+
     let q = quote::quote! {
         /// Do not have an explicit implementation for this structure.
-        pub struct #generate_ident #generics_with_lt {
+        pub struct #generate_ident #generics_with_lt #where_clause {
             #(#destruction,)*
         }
 
-        impl #generics #name #generics {
-            pub fn substitute(&mut self, mut f: impl FnOnce(&mut #generate_ident #generics)) {
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn substitute(&mut self, mut f: impl FnOnce(&mut #generate_ident #ty_generics_with_anonymous)) #watch_where_clause {
+                #(#snapshots)*
                 f(&mut #generate_ident {
                     #(#expanded,)*
-                })
+                });
+                #(#notifications)*
             }
 
-            pub fn try_substitute<E>(&mut self, mut f: impl FnOnce(&mut #generate_ident #generics) -> Result<(), E>) -> Result<(), E> {
+            pub fn try_substitute<E>(&mut self, mut f: impl FnOnce(&mut #generate_ident #ty_generics_with_anonymous) -> Result<(), E>) -> Result<(), E> #watch_where_clause {
+                #(#snapshots)*
                 f(&mut #generate_ident {
                     #(#expanded_cloned,)*
-                })
+                })?;
+                #(#notifications)*
+                Ok(())
             }
+
+            #async_try_substitute
+
+            #fields_mut
         }
+
+        #(#group_views)*
+
+        #pin
     };
 
     q.into()
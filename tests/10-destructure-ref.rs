@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use destructure::DestructureRef;
+
+#[derive(Debug, DestructureRef)]
+pub struct Book {
+    id: String,
+    name: String,
+    published_at: String,
+    author: String,
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        published_at: "2023/01/03".to_string(),
+        author: "author".to_string()
+    };
+
+    let des = book.as_destruct();
+
+    assert_eq!(des.name, "name");
+    assert_eq!(des.author, "author");
+
+    println!("{:?}", book);
+}
@@ -0,0 +1,40 @@
+#![allow(dead_code)]
+#![deny(missing_docs)]
+
+//! The hidden structures stay usable.
+
+use destructure::Destructure;
+
+/// A book.
+#[derive(Debug, Destructure)]
+#[destructure(doc_hidden, from_str, borrow_view)]
+pub struct Book {
+    id: String,
+    name: String,
+}
+
+/// A shelf, whose group structure is hidden along with the Destruct structure.
+#[derive(Debug, Destructure)]
+#[destructure(doc_hidden)]
+pub struct Shelf {
+    label: String,
+    #[destructure(group = "meta")]
+    created_at: u64,
+    #[destructure(group = "meta")]
+    updated_at: u64,
+}
+
+fn main() {
+    let book = Book { id: "123456789-abc".to_string(), name: "name".to_string() };
+    assert_eq!(book.borrow_destruct().name, "name");
+
+    let mut des: DestructBook = book.into_destruct();
+    des.set_from_str("name", "new name").unwrap();
+    assert_eq!(des.freeze().name, "new name");
+
+    let shelf = Shelf { label: "label".to_string(), created_at: 1, updated_at: 1 };
+    let shelf = shelf.reconstruct(|des| {
+        des.meta = DestructShelfMeta { created_at: 2, updated_at: 3 };
+    });
+    assert_eq!((shelf.created_at, shelf.updated_at), (2, 3));
+}
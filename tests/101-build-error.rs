@@ -0,0 +1,80 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Author(String);
+
+impl From<Author> for String {
+    fn from(author: Author) -> Self {
+        author.0
+    }
+}
+
+impl TryFrom<String> for Author {
+    type Error = BookError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        if name.is_empty() {
+            Err(BookError::EmptyAuthor)
+        } else {
+            Ok(Self(name))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pages(u32);
+
+impl From<Pages> for u32 {
+    fn from(pages: Pages) -> Self {
+        pages.0
+    }
+}
+
+impl TryFrom<u32> for Pages {
+    type Error = BookError;
+
+    fn try_from(pages: u32) -> Result<Self, Self::Error> {
+        if pages == 0 {
+            Err(BookError::NoPages)
+        } else {
+            Ok(Self(pages))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BookError {
+    EmptyAuthor,
+    NoPages,
+}
+
+
+#[derive(Debug, Destructure)]
+#[destructure(error = "BookError", build_error)]
+pub struct Book {
+    id: String,
+    #[destructure(try_from = "String")]
+    author: Author,
+    #[destructure(try_from = "u32")]
+    pages: Pages,
+}
+
+fn main() {
+    let book = Book { id: "123456789-abc".to_string(), author: Author("author".to_string()), pages: Pages(10) };
+
+    let mut des = book.into_destruct();
+    des.author = "reirokusanami".to_string();
+    let book = des.try_freeze_all().unwrap();
+    assert_eq!(book.author, Author("reirokusanami".to_string()));
+
+    let mut des = book.into_destruct();
+    des.author.clear();
+    des.pages = 0;
+    let err = Book::try_from(des).unwrap_err();
+    assert!(matches!(
+        err.failures.as_slice(),
+        [BookBuildFailure::Author(BookError::EmptyAuthor), BookBuildFailure::Pages(BookError::NoPages)]
+    ));
+}
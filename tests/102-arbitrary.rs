@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+use arbitrary::{Arbitrary, Unstructured};
+use destructure::Destructure;
+
+#[derive(Debug, PartialEq, Destructure)]
+#[destructure(arbitrary)]
+pub struct Point<T> {
+    x: u8,
+    #[destructure(rename = "height")]
+    y: u16,
+    label: T,
+    #[cfg(any())]
+    z: u32,
+    #[destructure(skip)]
+    cache: Option<u32>,
+}
+
+fn main() {
+    let data = [7u8, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+    let mut u = Unstructured::new(&data);
+    let des = DestructPoint::<bool>::arbitrary(&mut u).unwrap();
+
+    let mut expected = Unstructured::new(&data);
+    let x = u8::arbitrary(&mut expected).unwrap();
+    let y = u16::arbitrary(&mut expected).unwrap();
+    let label = bool::arbitrary(&mut expected).unwrap();
+    assert_eq!(des.x, x);
+    assert_eq!(des.height, y);
+    assert_eq!(des.label, label);
+
+    let point = des.freeze();
+    assert_eq!(point.x, 7);
+    assert_eq!(point.cache, None);
+}
@@ -0,0 +1,31 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+use zeroize::Zeroizing;
+
+#[derive(Debug, Destructure)]
+pub struct Account {
+    name: String,
+    #[destructure(zeroize)]
+    password: String,
+    #[destructure(zeroize)]
+    pin: [u8; 4],
+}
+
+fn main() {
+    let account = Account { name: "name".to_string(), password: "secret".to_string(), pin: [1, 2, 3, 4] };
+
+    let mut des = account.into_destruct();
+    let _: &Zeroizing<String> = &des.password;
+    assert_eq!(des.password.as_str(), "secret");
+    des.password.push_str(" changed");
+    des.pin[0] = 9;
+
+    let account = des.freeze();
+    assert_eq!(account.name, "name");
+    assert_eq!(account.password, "secret changed");
+    assert_eq!(account.pin, [9, 2, 3, 4]);
+
+    let account = account.reconstruct(|des| des.password.clear());
+    assert_eq!(account.password, "");
+}
@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+
+mod domain {
+    use destructure::Destructure;
+
+    #[derive(Debug, Destructure)]
+    pub struct User {
+        id: String,
+        name: String,
+        #[destructure(skip)]
+        password: String,
+    }
+
+    impl User {
+        pub fn new(id: impl Into<String>, name: impl Into<String>, password: impl Into<String>) -> Self {
+            Self { id: id.into(), name: name.into(), password: password.into() }
+        }
+
+        pub fn password(&self) -> &str {
+            &self.password
+        }
+    }
+}
+
+fn main() {
+    let user = domain::User::new("123456789-abc", "name", "secret");
+
+    let user = user.reconstruct(|des| {
+        des.name = "reirokusanami".to_string();
+    });
+
+    assert_eq!(user.password(), "secret");
+    assert_eq!(user.into_destruct().name, "reirokusanami");
+}
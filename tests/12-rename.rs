@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+pub struct Book {
+    id: String,
+    #[destructure(rename = "title")]
+    name: String,
+    #[destructure(rename = "r#type")]
+    kind: String,
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        kind: "novel".to_string(),
+    };
+
+    let mut des = book.into_destruct();
+    des.title = "new name".to_string();
+    des.r#type = "essay".to_string();
+
+    let book = des.freeze();
+
+    assert_eq!(book.name, "new name");
+    assert_eq!(book.kind, "essay");
+}
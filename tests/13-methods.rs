@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(methods(try_reconstruct))]
+pub struct Book {
+    id: String,
+    name: String,
+}
+
+fn main() -> Result<(), std::io::Error> {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+    };
+
+    let book = book.try_reconstruct(|des| -> Result<(), std::io::Error> {
+        des.name = "new name".to_string();
+        Ok(())
+    })?;
+
+    assert_eq!(book.into_destruct().freeze().name, "new name");
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+use destructure::{Destructure, DestructureRef, Mutation};
+
+#[derive(Destructure, DestructureRef, Mutation)]
+pub struct S<'a, T> {
+    r: &'a T,
+}
+
+#[derive(Destructure, DestructureRef, Mutation)]
+pub struct Bounded<'a, T: Clone, const N: usize>
+where
+    T: Debug,
+{
+    r: &'a T,
+    values: [T; N],
+}
+
+fn main() {
+    let value = 10;
+
+    let mut s = S { r: &value };
+    assert_eq!(*s.as_destruct().r, &10);
+
+    let other = 20;
+    s.substitute(|s| {
+        *s.r = &other;
+    });
+
+    let s = s.reconstruct(|des| {
+        assert_eq!(*des.r, 20);
+    });
+    assert_eq!(*s.into_destruct().r, 20);
+
+    let bounded = Bounded { r: &value, values: [1, 2, 3] };
+    let bounded = bounded.reconstruct(|des| {
+        des.values[0] = 5;
+    });
+    assert_eq!(bounded.as_destruct().values, &[5, 2, 3]);
+}
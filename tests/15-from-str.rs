@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(from_str)]
+pub struct Config {
+    host: String,
+    port: u16,
+    #[destructure(rename = "r#async")]
+    asynchronous: bool,
+}
+
+fn main() {
+    let config = Config {
+        host: "localhost".to_string(),
+        port: 8080,
+        asynchronous: false,
+    };
+
+    let mut des = config.into_destruct();
+    des.set_from_str("host", "example.com").unwrap();
+    des.set_from_str("port", "443").unwrap();
+    des.set_from_str("async", "true").unwrap();
+
+    assert_eq!(des.host, "example.com");
+    assert_eq!(des.port, 443);
+    assert!(des.r#async);
+
+    assert_eq!(
+        des.set_from_str("user", "admin"),
+        Err(DestructConfigParseError::UnknownField("user".to_string()))
+    );
+    assert!(matches!(
+        des.set_from_str("port", "http"),
+        Err(DestructConfigParseError::InvalidValue { field: "port", .. })
+    ));
+
+    let config = des.freeze();
+    assert_eq!(config.port, 443);
+}
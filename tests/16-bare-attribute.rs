@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure]
+pub struct Book {
+    #[destructure]
+    id: String,
+    #[destructure()]
+    name: String,
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+    };
+
+    let des = book.into_destruct();
+    assert_eq!(des.id, "123456789-abc");
+    assert_eq!(des.name, "name");
+
+    let book = des.freeze().reconstruct(|des| {
+        des.name = "new name".to_string();
+    });
+    assert_eq!(book.name, "new name");
+}
@@ -0,0 +1,20 @@
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(into_iter)]
+pub struct Rgb {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+fn main() {
+    let rgb = Rgb { red: 255, green: 128, blue: 0 };
+
+    let mut values = Vec::new();
+    for value in rgb.into_destruct() {
+        values.push(value);
+    }
+
+    assert_eq!(values, vec![255, 128, 0]);
+}
@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+
+mod domain {
+    use destructure::{Destructure, DestructureRef};
+
+    #[derive(Debug, Destructure, DestructureRef)]
+    pub struct User {
+        id: String,
+        #[destructure(rename = "display_name")]
+        name: String,
+        #[destructure(skip)]
+        password: String,
+    }
+
+    impl User {
+        pub fn new(id: impl Into<String>, name: impl Into<String>, password: impl Into<String>) -> Self {
+            Self { id: id.into(), name: name.into(), password: password.into() }
+        }
+    }
+}
+
+fn main() {
+    let user = domain::User::new("123456789-abc", "name", "secret");
+
+    let domain::DestructUserRef { id, display_name, .. } = user.as_destruct();
+    assert_eq!(id, "123456789-abc");
+    assert_eq!(display_name, "name");
+
+    let domain::DestructUser { id, display_name, .. } = user.into_destruct();
+    assert_eq!(id, "123456789-abc");
+    assert_eq!(display_name, "name");
+}
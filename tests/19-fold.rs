@@ -0,0 +1,23 @@
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(fold)]
+pub struct Stats {
+    sum: f64,
+    min: f64,
+    max: f64,
+}
+
+fn main() {
+    let stats = Stats { sum: 10.0, min: 1.5, max: 4.0 };
+
+    let total = stats.into_destruct().fold(0.0, |acc, value| acc + value);
+    assert_eq!(total, 15.5);
+
+    let stats = Stats { sum: 10.0, min: 1.5, max: 4.0 };
+    let order = stats.into_destruct().fold(Vec::new(), |mut acc, value| {
+        acc.push(value);
+        acc
+    });
+    assert_eq!(order, vec![10.0, 1.5, 4.0]);
+}
@@ -0,0 +1,42 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+pub struct Profile {
+    id: String,
+    #[destructure(skip_if = "predicate::no_nickname")]
+    nickname: Option<String>,
+    #[destructure(skip_if = "predicate::no_bio")]
+    bio: String,
+    #[destructure(skip)]
+    token: String,
+}
+
+mod predicate {
+    use super::DestructProfile;
+
+    pub fn no_nickname(des: &DestructProfile) -> bool {
+        des.nickname.is_none()
+    }
+
+    pub fn no_bio(des: &DestructProfile) -> bool {
+        des.bio.is_empty()
+    }
+}
+
+fn main() {
+    let profile = Profile {
+        id: "123456789-abc".to_string(),
+        nickname: None,
+        bio: "".to_string(),
+        token: "secret".to_string(),
+    };
+
+    let mut des = profile.into_destruct();
+    assert_eq!(des.visible_fields(), vec!["id"]);
+
+    des.nickname = Some("rei".to_string());
+    des.bio = "author".to_string();
+    assert_eq!(des.visible_fields(), vec!["id", "nickname", "bio"]);
+}
@@ -0,0 +1,21 @@
+#![allow(dead_code)]
+
+use destructure::{DestructureRef, Mutation};
+
+#[derive(Debug, DestructureRef, Mutation)]
+pub struct Book<'mutation, '__origin_mutation_lifetime> {
+    name: &'mutation str,
+    author: &'__origin_mutation_lifetime str,
+}
+
+fn main() {
+    let mut book = Book { name: "name", author: "author" };
+
+    book.substitute(|book| {
+        *book.name = "new name";
+        *book.author = "reirokusanami";
+    });
+
+    assert_eq!(book.name, "new name");
+    assert_eq!(*book.as_destruct().author, "reirokusanami");
+}
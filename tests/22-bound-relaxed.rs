@@ -0,0 +1,37 @@
+#![allow(dead_code)]
+
+use std::fmt::Display;
+use std::str::FromStr;
+use destructure::{Destructure, DestructureRef, Mutation};
+
+#[derive(Destructure, DestructureRef, Mutation)]
+#[destructure(bound_relaxed)]
+pub struct Container<T: ?Sized> {
+    len: usize,
+    value: Box<T>,
+}
+
+#[derive(Destructure)]
+#[destructure(bound_relaxed, from_str)]
+pub struct Setting<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    key: String,
+    value: T,
+}
+
+fn main() {
+    let container: Container<str> = Container { len: 4, value: "text".into() };
+    let container = container.reconstruct(|des| {
+        des.value = "other".into();
+        des.len = 5;
+    });
+    assert_eq!(&**container.as_destruct().value, "other");
+
+    let setting = Setting { key: "retry".to_string(), value: 3u8 };
+    let mut des = setting.into_destruct();
+    des.set_from_str("value", "5").unwrap();
+    assert_eq!(des.freeze().value, 5);
+}
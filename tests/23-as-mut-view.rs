@@ -0,0 +1,31 @@
+#![allow(dead_code)]
+
+use destructure::{Destructure, Mutation};
+
+#[derive(Debug, Destructure, Mutation)]
+#[destructure(as_mut_view)]
+pub struct Book {
+    id: String,
+    name: String,
+    published_at: String,
+}
+
+fn rename(view: DestructBookMut) {
+    *view.name = "new name".to_string();
+    *view.published_at = "2023/01/04".to_string();
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        published_at: "2023/01/03".to_string(),
+    };
+
+    let mut des = book.into_destruct();
+    rename(des.as_mut_view());
+
+    let book = des.freeze();
+    assert_eq!(book.name, "new name");
+    assert_eq!(book.published_at, "2023/01/04");
+}
@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(debug)]
+pub struct User {
+    id: u64,
+    name: String,
+    #[destructure(skip, redact)]
+    password: String,
+    #[destructure(redact)]
+    token: String,
+    #[destructure(skip)]
+    salt: String,
+}
+
+fn main() {
+    let user = User {
+        id: 1,
+        name: "name".to_string(),
+        password: "secret".to_string(),
+        token: "token".to_string(),
+        salt: "salt".to_string(),
+    };
+
+    let des = user.into_destruct();
+    assert_eq!(
+        format!("{:?}", des),
+        r#"DestructUser { id: 1, name: "name", password: <redacted>, token: <redacted>, .. }"#
+    );
+}
@@ -0,0 +1,23 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(size_hint)]
+pub struct Packet {
+    id: u64,
+    flags: u8,
+    payload: [u8; 16],
+    #[destructure(skip)]
+    checksum: u32,
+}
+
+fn main() {
+    let packet = Packet { id: 1, flags: 0, payload: [0; 16], checksum: 0 };
+
+    let des = packet.into_destruct();
+    assert_eq!(des.encoded_size_hint(), 8 + 1 + 16);
+
+    let buffer: Vec<u8> = Vec::with_capacity(des.encoded_size_hint());
+    assert!(buffer.capacity() >= 25);
+}
@@ -0,0 +1,39 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, PartialEq, Destructure)]
+#[destructure(transparent)]
+pub struct Id(u64);
+
+#[derive(Debug, PartialEq, Destructure)]
+#[destructure(transparent)]
+pub struct Name {
+    value: String,
+    #[destructure(skip)]
+    cache: Option<usize>,
+}
+
+fn main() {
+    let id = Id(1234);
+    let raw: u64 = id.into_destruct();
+    assert_eq!(raw, 1234);
+    assert_eq!(Id::from_destruct(raw), Id(1234));
+
+    let id = Id(1).reconstruct(|id| *id += 1);
+    assert_eq!(id, Id(2));
+
+    let name = Name { value: "name".to_string(), cache: Some(4) };
+    let name = Name::from_destruct(name.into_destruct() + " changed");
+    assert_eq!(name, Name { value: "name changed".to_string(), cache: None });
+
+    let name = Name { value: "name".to_string(), cache: Some(4) };
+    let name = name.reconstruct(|value| value.push_str(" changed"));
+    assert_eq!(name, Name { value: "name changed".to_string(), cache: Some(4) });
+
+    let name = name.try_reconstruct(|value| -> Result<(), ()> {
+        value.clear();
+        Ok(())
+    }).unwrap();
+    assert_eq!(name, Name { value: String::new(), cache: Some(4) });
+}
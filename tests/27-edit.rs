@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+pub struct Book {
+    id: String,
+    name: String,
+    author: String,
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        author: "author".to_string(),
+    };
+
+    let des = book.into_destruct()
+        .edit(|des| des.name = "new name".to_string())
+        .edit(|des| des.author = "reirokusanami".to_string());
+
+    assert_eq!(des.name, "new name");
+
+    let book = des.freeze();
+    assert_eq!(book.author, "reirokusanami");
+}
@@ -0,0 +1,23 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[repr(C, packed)]
+pub struct Header {
+    kind: u8,
+    length: u32,
+    name: String,
+}
+
+fn main() {
+    let header = Header { kind: 1, length: 16, name: "header".to_string() };
+
+    let header = header.reconstruct(|des| {
+        des.length = 32;
+    });
+
+    let des = header.into_destruct();
+    assert_eq!(des.length, 32);
+    assert_eq!(des.name, "header");
+}
@@ -0,0 +1,23 @@
+use destructure::Destructure;
+
+#[derive(Debug, PartialEq, Destructure)]
+#[destructure(map_all)]
+pub struct Point<T: Copy> {
+    x: T,
+    y: T,
+}
+
+fn main() {
+    let point = Point { x: 1.5f32, y: 2.5f32 };
+
+    let rounded = point.into_destruct().map_all(|v| v.round() as i32).freeze();
+    assert_eq!(rounded, Point { x: 2, y: 3 });
+
+    let mut count = 0;
+    let indexed = rounded.into_destruct().map_all(|v| {
+        count += 1;
+        (count, v)
+    });
+    assert_eq!(indexed.x, (1, 2));
+    assert_eq!(indexed.y, (2, 3));
+}
@@ -0,0 +1,87 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Author(String);
+
+impl From<Author> for String {
+    fn from(author: Author) -> Self {
+        author.0
+    }
+}
+
+impl TryFrom<String> for Author {
+    type Error = BookError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        if name.is_empty() {
+            Err(BookError::EmptyAuthor)
+        } else {
+            Ok(Self(name))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pages(u32);
+
+impl From<Pages> for u32 {
+    fn from(pages: Pages) -> Self {
+        pages.0
+    }
+}
+
+impl TryFrom<u32> for Pages {
+    type Error = BookError;
+
+    fn try_from(pages: u32) -> Result<Self, Self::Error> {
+        if pages == 0 {
+            Err(BookError::NoPages)
+        } else {
+            Ok(Self(pages))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BookError {
+    EmptyAuthor,
+    NoPages,
+}
+
+#[derive(Debug, Destructure)]
+#[destructure(error = "BookError")]
+pub struct Book {
+    id: String,
+    #[destructure(try_from = "String")]
+    author: Author,
+    #[destructure(try_from = "u32")]
+    pages: Pages,
+}
+
+#[derive(Debug, Destructure)]
+pub struct Review {
+    #[destructure(try_from = "String")]
+    author: Author,
+}
+
+fn main() {
+    let book = Book { id: "123456789-abc".to_string(), author: Author("author".to_string()), pages: Pages(10) };
+
+    let mut des = book.into_destruct();
+    des.author = "reirokusanami".to_string();
+    let book = des.try_freeze().unwrap();
+    assert_eq!(book.author, Author("reirokusanami".to_string()));
+
+    let mut des = book.into_destruct();
+    des.pages = 0;
+    assert_eq!(des.try_freeze().unwrap_err(), BookError::NoPages);
+
+    let review = Review { author: Author("author".to_string()) };
+    let result = review.try_reconstruct(|des| -> Result<(), BookError> {
+        des.author.clear();
+        Ok(())
+    });
+    assert_eq!(result.unwrap_err(), BookError::EmptyAuthor);
+}
@@ -0,0 +1,21 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+pub struct Book {
+    #[destructure(eq_skip)]
+    id: u64,
+    name: String,
+    #[destructure(eq_skip)]
+    created_at: String,
+}
+
+fn main() {
+    let a = Book { id: 1, name: "name".to_string(), created_at: "2023/01/03".to_string() }.into_destruct();
+    let b = Book { id: 2, name: "name".to_string(), created_at: "2023/01/04".to_string() }.into_destruct();
+    let c = Book { id: 1, name: "other".to_string(), created_at: "2023/01/03".to_string() }.into_destruct();
+
+    assert!(a == b);
+    assert!(a != c);
+}
@@ -0,0 +1,35 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(tracked)]
+pub struct Book {
+    id: String,
+    name: String,
+    author: String,
+    pages: u32,
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        author: "author".to_string(),
+        pages: 100,
+    };
+
+    let mut changed = Vec::new();
+    let book = book.reconstruct_tracked(|des| {
+        des.name = "new name".to_string();
+        des.author = "author".to_string();
+        des.pages += 1;
+    }, &mut changed);
+
+    assert_eq!(book.pages, 101);
+    if cfg!(debug_assertions) {
+        assert_eq!(changed, vec!["name", "pages"]);
+    } else {
+        assert!(changed.is_empty());
+    }
+}
@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(boxed)]
+pub struct Book {
+    id: String,
+    name: String,
+    content: [u8; 1024],
+}
+
+fn main() {
+    let book = Box::new(Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        content: [0; 1024],
+    });
+
+    let mut des = book.into_destruct_boxed();
+    des.name = "new name".to_string();
+    des.content[0] = 1;
+
+    let book: Box<Book> = des.freeze_boxed();
+    assert_eq!(book.name, "new name");
+    assert_eq!(book.content[0], 1);
+}
@@ -0,0 +1,43 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+pub struct Book {
+    id: String,
+    #[destructure(group = "meta")]
+    created_at: u64,
+    name: String,
+    #[destructure(group = "meta")]
+    updated_at: u64,
+    #[destructure(group = "stock", rename = "count")]
+    amount: u32,
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        created_at: 1,
+        name: "name".to_string(),
+        updated_at: 1,
+        amount: 10,
+    };
+
+    let mut des = book.into_destruct();
+    assert_eq!(des.meta.created_at, 1);
+    des.meta.updated_at = 2;
+    des.stock.count -= 1;
+    des.name = "new name".to_string();
+
+    let book = des.freeze();
+    assert_eq!(book.created_at, 1);
+    assert_eq!(book.updated_at, 2);
+    assert_eq!(book.amount, 9);
+    assert_eq!(book.name, "new name");
+
+    let book = book.reconstruct(|before| {
+        before.meta = DestructBookMeta { created_at: 10, updated_at: 20 };
+    });
+    assert_eq!(book.created_at, 10);
+    assert_eq!(book.updated_at, 20);
+}
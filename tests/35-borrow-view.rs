@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(borrow_view)]
+pub struct Book<'a, T> {
+    id: String,
+    #[destructure(rename = "title")]
+    name: &'a str,
+    content: T,
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name",
+        content: vec![0u8; 16],
+    };
+
+    let view: DestructBookRef<'_, '_, Vec<u8>> = book.borrow_destruct();
+    assert_eq!(view.id, "123456789-abc");
+    assert_eq!(*view.title, "name");
+    assert_eq!(view.content.len(), 16);
+
+    let des = book.into_destruct();
+    assert_eq!(des.title, "name");
+}
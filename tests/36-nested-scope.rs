@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+const _: () = {
+    #[derive(Destructure)]
+    pub struct Book {
+        id: String,
+        name: String,
+    }
+
+    fn rename(book: Book) -> Book {
+        let mut des: DestructBook = book.into_destruct();
+        des.name = "new name".to_string();
+        des.freeze()
+    }
+};
+
+fn make() -> (String, String) {
+    #[derive(Destructure)]
+    struct Author {
+        id: String,
+        name: String,
+    }
+
+    let author = Author { id: "author-1".to_string(), name: "name".to_string() };
+    let author = author.reconstruct(|before: &mut DestructAuthor| {
+        before.name = "new name".to_string();
+    });
+    let DestructAuthor { id, name } = author.into_destruct();
+    (id, name)
+}
+
+fn main() {
+    assert_eq!(make(), ("author-1".to_string(), "new name".to_string()));
+}
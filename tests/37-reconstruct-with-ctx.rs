@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+pub struct Book<C> {
+    id: String,
+    updated_at: u64,
+    content: C,
+}
+
+struct Clock(u64);
+
+fn touch<C>(before: &mut DestructBook<C>, clock: &Clock) {
+    before.updated_at = clock.0;
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        updated_at: 0,
+        content: "content",
+    };
+
+    let book = book.reconstruct_with_ctx(&Clock(10), touch);
+    assert_eq!(book.updated_at, 10);
+
+    let book = book.reconstruct_with_ctx(Clock(20), |before, clock| touch(before, &clock));
+    assert_eq!(book.updated_at, 20);
+}
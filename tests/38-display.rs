@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(display = "{title} by {author} ({pages:>4} pages, {{draft}})")]
+pub struct Book<A> {
+    id: String,
+    #[destructure(rename = "title")]
+    name: String,
+    author: A,
+    pages: u32,
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        author: "author",
+        pages: 42,
+    };
+
+    let des = book.into_destruct();
+    assert_eq!(des.to_string(), "name by author (  42 pages, {draft})");
+}
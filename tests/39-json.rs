@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(json)]
+pub struct Book<T> {
+    id: String,
+    #[destructure(rename = "title")]
+    name: String,
+    content: T,
+    created_at: u64,
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        content: vec![1u8, 2, 3],
+        created_at: 1,
+    };
+
+    let value = book.into_destruct().to_json_value();
+    assert_eq!(value["title"], "name");
+    assert_eq!(value["created_at"], 1);
+
+    let des = DestructBook::<Vec<u8>>::from_json_value(value).unwrap();
+    let book = des.freeze();
+    assert_eq!(book.content, vec![1, 2, 3]);
+
+    assert!(DestructBook::<Vec<u8>>::from_json_value(serde_json::json!({ "id": 1 })).is_err());
+}
@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(schema)]
+pub struct Book<T> {
+    id: String,
+    #[destructure(rename = "title")]
+    name: String,
+    #[destructure(skip)]
+    secret: String,
+    content: T,
+}
+
+fn main() {
+    assert_eq!(DestructBook::<u32>::schema(), vec![
+        ("id", std::any::type_name::<String>()),
+        ("title", std::any::type_name::<String>()),
+        ("content", "u32"),
+    ]);
+}
@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+
+use destructure::{Destructure, DestructureRef};
+
+#[derive(Debug, Destructure, DestructureRef)]
+pub struct Book {
+    id: String,
+    name: String,
+    #[destructure(skip_drop, init = "Vec::new()")]
+    cache: Vec<String>,
+    #[destructure(skip_drop, init = "Some(0)")]
+    hits: Option<u32>,
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        cache: vec!["cached".to_string()],
+        hits: Some(10),
+    };
+
+    let DestructBookRef { id, name } = book.as_destruct();
+    assert_eq!((id.as_str(), name.as_str()), ("123456789-abc", "name"));
+
+    let DestructBook { id, name } = book.into_destruct();
+    let book = DestructBook { id, name: format!("new {}", name) }.freeze();
+    assert_eq!(book.name, "new name");
+    assert!(book.cache.is_empty());
+    assert_eq!(book.hits, Some(0));
+
+    let book = book.reconstruct(|before| before.id = "987654321-abc".to_string());
+    assert_eq!(book.id, "987654321-abc");
+}
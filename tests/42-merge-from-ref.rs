@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use destructure::DestructureRef;
+
+#[derive(Debug, DestructureRef)]
+#[destructure(merge_from_ref)]
+pub struct Book<T> {
+    id: String,
+    #[destructure(rename = "title")]
+    name: String,
+    content: T,
+}
+
+fn main() {
+    let snapshot = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        content: vec![1u8, 2, 3],
+    };
+    let mut book = Book {
+        id: String::new(),
+        name: String::new(),
+        content: Vec::new(),
+    };
+
+    book.merge_from_ref(&snapshot.as_destruct());
+    assert_eq!(book.id, "123456789-abc");
+    assert_eq!(book.name, "name");
+    assert_eq!(book.content, vec![1, 2, 3]);
+}
@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(schema)]
+pub struct Book {
+    id: String,
+    #[destructure(order = 2)]
+    name: String,
+    author: String,
+    #[destructure(order = 1)]
+    stocked_at: String,
+}
+
+fn main() {
+    let names = DestructBook::schema().into_iter().map(|(name, _)| name).collect::<Vec<_>>();
+    assert_eq!(names, vec!["stocked_at", "name", "id", "author"]);
+
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        author: "author".to_string(),
+        stocked_at: "2023/01/03".to_string(),
+    };
+    let DestructBook { stocked_at, name, id, author } = book.into_destruct();
+    assert_eq!((stocked_at.as_str(), name.as_str(), id.as_str(), author.as_str()), ("2023/01/03", "name", "123456789-abc", "author"));
+}
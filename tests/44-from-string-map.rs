@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(from_string_map)]
+pub struct Book {
+    id: String,
+    #[destructure(rename = "title")]
+    name: String,
+    pages: u32,
+    #[destructure(skip)]
+    hits: u64,
+}
+
+fn map(entries: &[(&str, &str)]) -> HashMap<String, String> {
+    entries.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+}
+
+fn main() {
+    let des = DestructBook::try_from(map(&[("id", "123456789-abc"), ("title", "name"), ("pages", "42")])).unwrap();
+    let book = des.freeze();
+    assert_eq!(book.name, "name");
+    assert_eq!(book.pages, 42);
+    assert_eq!(book.hits, 0);
+
+    let missing = DestructBook::try_from(map(&[("id", "123456789-abc"), ("title", "name")]));
+    assert_eq!(missing.err(), Some(DestructBookParseError::MissingField("pages")));
+
+    let unknown = DestructBook::try_from(map(&[("id", "1"), ("title", "name"), ("pages", "42"), ("hits", "1")]));
+    assert_eq!(unknown.err(), Some(DestructBookParseError::UnknownField("hits".to_string())));
+
+    let invalid = DestructBook::try_from(map(&[("id", "1"), ("title", "name"), ("pages", "many")]));
+    assert!(matches!(invalid, Err(DestructBookParseError::InvalidValue { field: "pages", .. })));
+}
@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use std::marker::PhantomData;
+use destructure::Destructure;
+
+pub struct NumId<T: ?Sized> {
+    id: i32,
+    _mark: PhantomData<T>
+}
+
+impl<T: ?Sized> NumId<T> {
+    pub fn new(id: impl Into<i32>) -> Self {
+        Self { id: id.into(), _mark: PhantomData }
+    }
+}
+
+impl<T: ?Sized> TryFrom<i32> for NumId<T> {
+    type Error = String;
+
+    fn try_from(id: i32) -> Result<Self, Self::Error> {
+        match id {
+            ..=0 => Err(format!("invalid id `{}`", id)),
+            _ => Ok(Self::new(id)),
+        }
+    }
+}
+
+impl<T: ?Sized> From<NumId<T>> for i32 {
+    fn from(id: NumId<T>) -> Self {
+        id.id
+    }
+}
+
+#[derive(Destructure)]
+pub struct Book<'a, T: ?Sized> {
+    #[destructure(try_from = "i32")]
+    id: NumId<T>,
+    name: String,
+    #[destructure(skip_drop, init = "None")]
+    cache: Option<&'a str>,
+}
+
+fn main() {
+    let book: Book<'_, str> = Book { id: NumId::new(1), name: "name".to_string(), cache: Some("cached") };
+
+    let mut des = book.into_destruct();
+    des.id += 1;
+
+    let book = des.try_freeze().unwrap();
+    assert_eq!(book.id.id, 2);
+    assert!(book.cache.is_none());
+}
@@ -0,0 +1,51 @@
+#![allow(dead_code)]
+
+use std::future::Future;
+use std::task::{Context, Poll, Waker};
+use destructure::Mutation;
+
+#[derive(Debug, Mutation)]
+#[mutation(async)]
+pub struct Book<'a> {
+    id: String,
+    name: &'a str,
+}
+
+async fn validate(name: &str) -> Result<(), String> {
+    match name.is_empty() {
+        true => Err("name must not be empty".to_string()),
+        false => Ok(()),
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output
+        }
+    }
+}
+
+fn main() {
+    let mut book = Book { id: "123456789-abc".to_string(), name: "name" };
+
+    let updated = block_on(book.async_try_substitute(|book| async move {
+        validate("new name").await?;
+        *book.name = "new name";
+        book.id.push_str("-1");
+        Ok::<_, String>(())
+    }));
+    assert!(updated.is_ok());
+    assert_eq!(book.name, "new name");
+    assert_eq!(book.id, "123456789-abc-1");
+
+    let rejected = block_on(book.async_try_substitute(|book| async move {
+        validate("").await?;
+        *book.name = "";
+        Ok(())
+    }));
+    assert_eq!(rejected, Err("name must not be empty".to_string()));
+    assert_eq!(book.name, "new name");
+}
@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+use destructure::{Destructure, DestructureRef, Mutation};
+
+#[derive(Destructure, DestructureRef, Mutation)]
+pub struct Wrapper<I: Iterator>
+    where I::Item: Clone
+{
+    iter: I,
+    count: usize,
+}
+
+fn main() {
+    let mut wrapper = Wrapper { iter: vec![1, 2, 3].into_iter(), count: 0 };
+
+    wrapper.substitute(|wrapper| {
+        *wrapper.count = wrapper.iter.by_ref().count();
+    });
+    assert_eq!(wrapper.count, 3);
+
+    let des = wrapper.as_destruct();
+    assert_eq!(*des.count, 3);
+
+    let wrapper = wrapper.reconstruct(|before| {
+        before.iter = vec![4].into_iter();
+    });
+    assert_eq!(wrapper.iter.collect::<Vec<_>>(), vec![4]);
+}
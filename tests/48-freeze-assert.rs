@@ -0,0 +1,22 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(assert = "self.age < 150", assert = "!self.name.is_empty()")]
+pub struct Person {
+    name: String,
+    age: u32,
+}
+
+fn main() {
+    let person = Person { name: "name".to_string(), age: 20 };
+    let person = person.reconstruct(|before| before.age += 1);
+    assert_eq!(person.age, 21);
+
+    std::panic::set_hook(Box::new(|_| {}));
+    let violated = std::panic::catch_unwind(|| {
+        person.reconstruct(|before| before.age = 200)
+    });
+    assert_eq!(violated.is_err(), cfg!(debug_assertions));
+}
@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+use std::borrow::Cow;
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+pub struct Book {
+    id: String,
+    #[destructure(cow_accessor)]
+    content: Vec<u8>,
+    #[destructure(cow_accessor, rename = "title")]
+    name: String,
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        content: vec![0; 1024],
+        name: "name".to_string(),
+    };
+
+    let content = book.content_cow();
+    assert!(matches!(content, Cow::Borrowed(_)));
+
+    let mut title = book.title_cow();
+    title.to_mut().push_str(" (copy)");
+    assert_eq!(title.as_str(), "name (copy)");
+    assert_eq!(book.name, "name");
+}
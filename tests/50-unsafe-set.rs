@@ -0,0 +1,33 @@
+#![allow(dead_code)]
+
+mod book {
+    use destructure::Destructure;
+
+    #[derive(Debug, Destructure)]
+    pub struct Book {
+        pub id: String,
+        #[destructure(skip, unsafe_set)]
+        revision: u32,
+    }
+
+    impl Book {
+        pub fn new(id: &str) -> Self {
+            Self { id: id.to_string(), revision: 0 }
+        }
+
+        pub fn revision(&self) -> u32 {
+            self.revision
+        }
+    }
+}
+
+fn main() {
+    let book = book::Book::new("123456789-abc");
+
+    let mut des = book.into_destruct();
+    // SAFETY: the revision is restored from a trusted snapshot.
+    unsafe { des.set_revision(42) };
+
+    let book = des.freeze();
+    assert_eq!(book.revision(), 42);
+}
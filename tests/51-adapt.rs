@@ -0,0 +1,37 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+mod other {
+    pub struct Dto {
+        pub identifier: String,
+        pub name: String,
+    }
+
+    pub struct Summary {
+        pub id: String,
+        pub title: String,
+    }
+}
+
+#[derive(Debug, Destructure)]
+#[destructure(adapt(target = "other::Dto", map(id = "identifier")))]
+#[destructure(adapt(target = "other::Summary", map(name = "title")))]
+pub struct Book {
+    id: String,
+    name: String,
+}
+
+fn book() -> Book {
+    Book { id: "123456789-abc".to_string(), name: "name".to_string() }
+}
+
+fn main() {
+    let dto = other::Dto::from(book());
+    assert_eq!(dto.identifier, "123456789-abc");
+    assert_eq!(dto.name, "name");
+
+    let summary: other::Summary = book().into();
+    assert_eq!(summary.id, "123456789-abc");
+    assert_eq!(summary.title, "name");
+}
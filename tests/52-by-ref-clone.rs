@@ -0,0 +1,35 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(by_ref_clone)]
+pub struct Book<T> {
+    id: String,
+    #[destructure(rename = "title")]
+    name: String,
+    content: T,
+}
+
+#[derive(Debug, Destructure)]
+#[destructure(by_ref_clone)]
+pub struct Author {
+    name: String,
+    #[destructure(group = "meta")]
+    created_at: u64,
+}
+
+fn main() {
+    let book = Book { id: "123456789-abc".to_string(), name: "name".to_string(), content: vec![1u8] };
+
+    let mut des = book.to_destruct();
+    des.title = "new name".to_string();
+    assert_eq!(book.to_destruct().title, "name");
+
+    let book = book.reconstruct(|before| before.content.push(2));
+    assert_eq!(book.content, vec![1, 2]);
+    assert_eq!(des.freeze().content, vec![1]);
+
+    let author = Author { name: "author".to_string(), created_at: 1 };
+    assert_eq!(author.to_destruct().meta.created_at, 1);
+}
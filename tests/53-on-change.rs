@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+
+use std::cell::RefCell;
+use destructure::Mutation;
+
+thread_local! {
+    static CHANGES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn name_changed(name: &String) {
+    CHANGES.with(|changes| changes.borrow_mut().push(format!("name: {}", name)));
+}
+
+fn stock_changed(stock: &u32) {
+    CHANGES.with(|changes| changes.borrow_mut().push(format!("stock: {}", stock)));
+}
+
+#[derive(Debug, Mutation)]
+pub struct Book {
+    id: String,
+    #[mutation(on_change = "name_changed")]
+    name: String,
+    #[mutation(on_change = "stock_changed")]
+    stock: u32,
+}
+
+fn main() {
+    let mut book = Book { id: "123456789-abc".to_string(), name: "name".to_string(), stock: 1 };
+
+    book.substitute(|book| {
+        *book.name = "new name".to_string();
+        *book.stock = 1;
+    });
+
+    let failed = book.try_substitute(|book| {
+        *book.stock = 0;
+        Err("out of stock")
+    });
+    assert!(failed.is_err());
+
+    book.try_substitute(|book| {
+        *book.stock += 5;
+        Ok::<_, ()>(())
+    }).unwrap();
+
+    CHANGES.with(|changes| assert_eq!(*changes.borrow(), vec!["name: new name", "stock: 5"]));
+}
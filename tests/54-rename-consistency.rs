@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(from_str, from_string_map, schema, debug, display = "{type}: {title}", borrow_view)]
+pub struct Book {
+    #[destructure(rename = "title")]
+    name: String,
+    #[destructure(rename = "r#type")]
+    kind: String,
+}
+
+fn main() {
+    let book = Book { name: "name".to_string(), kind: "novel".to_string() };
+
+    let view = book.borrow_destruct();
+    assert_eq!((view.title.as_str(), view.r#type.as_str()), ("name", "novel"));
+
+    let mut des = book.into_destruct();
+    assert_eq!(des.title, "name");
+    des.set_from_str("title", "new name").unwrap();
+    des.set_from_str("type", "essay").unwrap();
+    assert!(des.set_from_str("name", "name").is_err());
+
+    assert_eq!(des.to_string(), "essay: new name");
+    assert_eq!(format!("{:?}", des), r#"DestructBook { title: "new name", type: "essay" }"#);
+    assert_eq!(DestructBook::schema().iter().map(|(name, _)| *name).collect::<Vec<_>>(), vec!["title", "type"]);
+
+    let map = HashMap::from([("title".to_string(), "name".to_string()), ("type".to_string(), "novel".to_string())]);
+    let book = DestructBook::try_from(map).unwrap().freeze();
+    assert_eq!((book.name.as_str(), book.kind.as_str()), ("name", "novel"));
+}
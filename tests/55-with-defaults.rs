@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(with_defaults)]
+pub struct Config<P> {
+    host: String,
+    port: u16,
+    #[destructure(rename = "threads")]
+    workers: usize,
+    plugin: P,
+}
+
+#[derive(Debug, Default, Destructure)]
+#[destructure(with_defaults)]
+pub struct Internal<T> {
+    name: String,
+    #[destructure(skip)]
+    state: T,
+}
+
+#[derive(Debug, Default, Destructure)]
+#[destructure(with_defaults, bound_relaxed)]
+pub struct Relaxed<T: Default> {
+    name: String,
+    value: T,
+}
+
+impl<P: Default> Default for Config<P> {
+    fn default() -> Self {
+        Self { host: "localhost".to_string(), port: 8080, workers: 4, plugin: P::default() }
+    }
+}
+
+fn main() {
+    let config = DestructConfig::<u8>::with_defaults(DestructConfigPatch {
+        port: Some(3000),
+        threads: Some(8),
+        ..Default::default()
+    }).freeze();
+
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.port, 3000);
+    assert_eq!(config.workers, 8);
+    assert_eq!(config.plugin, 0);
+
+    let internal = DestructInternal::<u32>::with_defaults(DestructInternalPatch { name: Some("name".to_string()), ..Default::default() }).freeze();
+    assert_eq!(internal.name, "name");
+
+    let relaxed = DestructRelaxed::<u32>::with_defaults(DestructRelaxedPatch { value: Some(7), ..Default::default() }).freeze();
+    assert_eq!(relaxed.name, "");
+    assert_eq!(relaxed.value, 7);
+}
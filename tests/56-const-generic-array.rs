@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+use destructure::{Destructure, DestructureRef, Mutation};
+
+#[derive(Debug, Destructure, DestructureRef, Mutation)]
+pub struct Buf<const N: usize> {
+    data: [u8; N],
+    len: usize,
+}
+
+fn main() {
+    let mut buf = Buf::<4> { data: [0; 4], len: 0 };
+
+    buf.substitute(|buf| {
+        buf.data[0] = 1;
+        *buf.len = 1;
+    });
+
+    let view: DestructBufRef<'_, 4> = buf.as_destruct();
+    assert_eq!(view.data, &[1, 0, 0, 0]);
+
+    let mut des: DestructBuf<4> = buf.into_destruct();
+    des.data[1] = 2;
+    des.len = 2;
+
+    let buf = des.freeze();
+    assert_eq!(buf.data, [1, 2, 0, 0]);
+    assert_eq!(buf.len, 2);
+}
@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(index_usize)]
+pub struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+fn main() {
+    let rgb = Rgb { r: 1, g: 2, b: 3 };
+
+    let mut des = rgb.into_destruct();
+    assert_eq!((des[0], des[1], des[2]), (1, 2, 3));
+
+    des[1] = 20;
+    assert_eq!(des.g, 20);
+
+    std::panic::set_hook(Box::new(|_| {}));
+    let out_of_range = std::panic::catch_unwind(|| des[3]);
+    assert!(out_of_range.is_err());
+}
@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+
+mod book {
+    use destructure::Destructure;
+
+    #[derive(Debug, Destructure)]
+    #[destructure(pub_fields = false)]
+    pub struct Book {
+        id: String,
+        #[destructure(rename = "title")]
+        name: String,
+        #[destructure(skip)]
+        revision: u32,
+    }
+
+    impl Book {
+        pub fn new(id: &str, name: &str) -> Self {
+            Self { id: id.to_string(), name: name.to_string(), revision: 0 }
+        }
+
+        pub fn renamed(self, name: &str) -> Self {
+            let DestructBook { id, revision, .. } = self.into_destruct();
+            DestructBook { id, title: name.to_string(), revision: revision + 1 }.freeze()
+        }
+    }
+}
+
+fn main() {
+    let book = book::Book::new("123456789-abc", "name").renamed("new name");
+
+    let des = book.into_destruct();
+    assert_eq!(des.id(), "123456789-abc");
+    assert_eq!(des.title(), "new name");
+}
@@ -0,0 +1,39 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(helpers)]
+pub struct Book {
+    id: String,
+    name: String,
+}
+
+#[derive(Debug, Destructure)]
+#[destructure(helpers)]
+pub struct BookCover<T> {
+    color: T,
+}
+
+pub struct Shelf {
+    featured: Option<Book>,
+}
+
+fn main() {
+    let mut shelf = Shelf {
+        featured: Some(Book { id: "123456789-abc".to_string(), name: "name".to_string() }),
+    };
+
+    book_helpers::reconstruct_option(&mut shelf.featured, |before| {
+        before.name = "new name".to_string();
+    });
+    assert_eq!(shelf.featured.as_ref().map(|book| book.name.as_str()), Some("new name"));
+
+    let mut empty: Option<Book> = None;
+    book_helpers::reconstruct_option(&mut empty, |_| unreachable!());
+    assert!(empty.is_none());
+
+    let mut cover = Some(BookCover { color: 1u8 });
+    book_cover_helpers::reconstruct_option(&mut cover, |before| before.color += 1);
+    assert_eq!(cover.map(|cover| cover.color), Some(2));
+}
@@ -0,0 +1,33 @@
+#![allow(dead_code)]
+
+mod book {
+    use destructure::Destructure;
+
+    #[derive(Debug, Destructure)]
+    #[destructure(sealed)]
+    pub struct Book {
+        id: String,
+        name: String,
+    }
+
+    impl Book {
+        pub fn new(id: &str, name: &str) -> Self {
+            Self { id: id.to_string(), name: name.to_string() }
+        }
+
+        pub fn name(&self) -> &str {
+            &self.name
+        }
+    }
+}
+
+fn main() {
+    let book = book::Book::new("123456789-abc", "name");
+
+    let mut des = book.into_destruct();
+    des.name = "new name".to_string();
+    assert_eq!(des.id, "123456789-abc");
+
+    let book = des.freeze();
+    assert_eq!(book.name(), "new name");
+}
@@ -0,0 +1,36 @@
+use std::collections::{HashMap, HashSet};
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+pub struct Book {
+    name: String,
+    #[destructure(collection)]
+    tags: Vec<String>,
+    #[destructure(collection)]
+    authors: HashSet<String>,
+    #[destructure(collection)]
+    ratings: std::collections::HashMap<String, u8>,
+}
+
+fn main() {
+    let book = Book {
+        name: "name".to_string(),
+        tags: vec!["novel".to_string()],
+        authors: HashSet::new(),
+        ratings: HashMap::new(),
+    };
+
+    let mut des = book.into_destruct();
+    des.push_tags("mystery".to_string());
+    assert!(des.insert_authors("author".to_string()));
+    assert!(!des.insert_authors("author".to_string()));
+    assert_eq!(des.insert_ratings("reader".to_string(), 3), None);
+    assert_eq!(des.insert_ratings("reader".to_string(), 5), Some(3));
+
+    let book = des.freeze();
+    assert_eq!(book.name, "name");
+    assert_eq!(book.tags, vec!["novel".to_string(), "mystery".to_string()]);
+    assert_eq!(book.authors.len(), 1);
+    assert_eq!(book.ratings.get("reader"), Some(&5));
+}
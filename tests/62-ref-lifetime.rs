@@ -0,0 +1,21 @@
+use destructure::DestructureRef;
+
+#[derive(DestructureRef)]
+#[destructure(lifetime = "view")]
+pub struct Book<'a> {
+    name: &'a str,
+    author: String,
+}
+
+fn author_of<'view>(des: &DestructBookRef<'view, '_>) -> &'view str {
+    des.author
+}
+
+fn main() {
+    let name = String::from("name");
+    let book = Book { name: &name, author: "author".to_string() };
+
+    let des = book.as_destruct();
+    assert_eq!(*des.name, "name");
+    assert_eq!(author_of(&des), "author");
+}
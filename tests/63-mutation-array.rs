@@ -0,0 +1,19 @@
+use destructure::Mutation;
+
+#[derive(Debug, PartialEq, Mutation)]
+#[mutation(array)]
+pub struct Point {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+fn main() {
+    let mut point = Point { x: -5, y: 3, z: 12 };
+
+    for coordinate in point.fields_mut() {
+        *coordinate = (*coordinate).clamp(0, 10);
+    }
+
+    assert_eq!(point, Point { x: 0, y: 3, z: 10 });
+}
@@ -0,0 +1,24 @@
+use destructure::Destructure;
+
+#[derive(Debug, Clone, Destructure)]
+#[destructure(checksum)]
+pub struct Book {
+    name: String,
+    pages: u32,
+    #[destructure(skip)]
+    cache: Vec<u8>,
+}
+
+fn main() {
+    let book = Book { name: "name".to_string(), pages: 120, cache: vec![1, 2, 3] };
+    let other = Book { cache: Vec::new(), ..book.clone() };
+
+    let des = book.into_destruct();
+    let checksum = des.checksum();
+    assert_eq!(checksum, des.checksum());
+    assert_eq!(checksum, other.clone().into_destruct().checksum());
+
+    let mut des = other.into_destruct();
+    des.pages = 121;
+    assert_ne!(checksum, des.checksum());
+}
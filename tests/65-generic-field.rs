@@ -0,0 +1,21 @@
+use std::fmt::Debug;
+
+use destructure::Destructure;
+
+#[derive(Debug, PartialEq, Destructure)]
+pub struct Wrapper<'a, T: Debug, const N: usize> where T: PartialEq {
+    #[destructure(generic_field)]
+    value: T,
+    meta: &'a str,
+    codes: [u8; N],
+}
+
+fn main() {
+    let wrapper = Wrapper { value: 42, meta: "meta", codes: [1, 2] };
+
+    let mapped: Wrapper<String, 2> = wrapper.map_value(|value| value.to_string());
+    assert_eq!(mapped, Wrapper { value: "42".to_string(), meta: "meta", codes: [1, 2] });
+
+    let des = mapped.into_destruct();
+    assert_eq!(des.value, "42");
+}
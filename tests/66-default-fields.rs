@@ -0,0 +1,20 @@
+use destructure::Destructure;
+
+#[derive(Debug, PartialEq, Destructure)]
+#[destructure(default_fields)]
+pub struct Book {
+    name: String,
+    pages: u32,
+    #[destructure(skip)]
+    tags: Vec<String>,
+}
+
+fn main() {
+    let mut des = DestructBook::default();
+    assert_eq!(des.name, "");
+    assert_eq!(des.pages, 0);
+
+    des.name = "name".to_string();
+    des.pages = 120;
+    assert_eq!(des.freeze(), Book { name: "name".to_string(), pages: 120, tags: Vec::new() });
+}
@@ -0,0 +1,51 @@
+use destructure::Destructure;
+
+#[derive(Debug, PartialEq, Destructure)]
+#[destructure(freeze_via = "Book::assemble", error = "String")]
+pub struct Book {
+    #[destructure(order = 0)]
+    pages: u32,
+    name: String,
+    #[destructure(try_from = "String")]
+    isbn: Isbn,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Isbn(String);
+
+impl TryFrom<String> for Isbn {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.len() {
+            13 => Ok(Isbn(value)),
+            _ => Err(format!("invalid isbn `{}`", value))
+        }
+    }
+}
+
+impl From<Isbn> for String {
+    fn from(value: Isbn) -> Self {
+        value.0
+    }
+}
+
+impl Book {
+    fn assemble(pages: u32, name: String, isbn: Isbn) -> Self {
+        Self { pages: pages.max(1), name: name.trim().to_string(), isbn }
+    }
+}
+
+fn main() {
+    let book = Book { pages: 120, name: "name".to_string(), isbn: Isbn("9784000000000".to_string()) };
+
+    let mut des = book.into_destruct();
+    des.pages = 0;
+    des.name = "  new name ".to_string();
+    let book = des.try_freeze().unwrap();
+    assert_eq!(book, Book { pages: 1, name: "new name".to_string(), isbn: Isbn("9784000000000".to_string()) });
+
+    let mut des = book.into_destruct();
+    des.isbn = "invalid".to_string();
+    assert_eq!(des.try_freeze(), Err("invalid isbn `invalid`".to_string()));
+}
@@ -0,0 +1,30 @@
+use destructure::Destructure;
+
+#[derive(Debug, Clone, PartialEq, Destructure)]
+#[destructure(diff_patch)]
+pub struct Book {
+    name: String,
+    pages: u32,
+    author: String,
+}
+
+fn main() {
+    let book = Book { name: "name".to_string(), pages: 120, author: "author".to_string() };
+    let before = book.clone().into_destruct();
+
+    let mut after = book.clone().into_destruct();
+    after.name = "new name".to_string();
+    after.pages = 150;
+
+    let patch = before.diff_patch(&after);
+    assert_eq!(patch.name.as_deref(), Some("new name"));
+    assert_eq!(patch.pages, Some(150));
+    assert_eq!(patch.author, None);
+
+    let mut replica = book.into_destruct();
+    replica.apply_patch(patch);
+    assert_eq!(replica.freeze(), after.freeze());
+
+    let unchanged = before.diff_patch(&before);
+    assert!(unchanged.name.is_none() && unchanged.pages.is_none() && unchanged.author.is_none());
+}
@@ -0,0 +1,31 @@
+use destructure::{Destructure, DestructureRef, Mutation};
+
+#[derive(Debug, Clone, PartialEq, Destructure, DestructureRef, Mutation)]
+pub struct Node {
+    value: i32,
+    next: Option<Box<Node>>,
+    children: Vec<Self>,
+}
+
+fn main() {
+    let node = Node {
+        value: 1,
+        next: Some(Box::new(Node { value: 2, next: None, children: Vec::new() })),
+        children: vec![Node { value: 3, next: None, children: Vec::new() }],
+    };
+
+    let des = node.clone().into_destruct();
+    let next: &Option<Box<Node>> = &des.next;
+    assert_eq!(next.as_ref().map(|next| next.value), Some(2));
+    assert_eq!(des.freeze(), node);
+
+    let view = node.as_destruct();
+    assert_eq!(view.children[0].value, 3);
+
+    let node = node.reconstruct(|des| {
+        des.children.push(Node { value: 4, next: None, children: Vec::new() });
+        des.next = None;
+    });
+    assert_eq!(node.children.len(), 2);
+    assert_eq!(node.next, None);
+}
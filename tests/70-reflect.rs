@@ -0,0 +1,21 @@
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(reflect)]
+pub struct Book {
+    name: String,
+    #[destructure(rename = "page_count")]
+    pages: u32,
+    #[destructure(skip)]
+    cache: Vec<u8>,
+}
+
+fn main() {
+    let book = Book { name: "name".to_string(), pages: 120, cache: Vec::new() };
+
+    let metas = book.into_destruct().reflect();
+    assert_eq!(metas, vec![
+        DestructBookFieldMeta { name: "name", type_name: "alloc::string::String", value: "name".to_string() },
+        DestructBookFieldMeta { name: "page_count", type_name: "u32", value: "120".to_string() },
+    ]);
+}
@@ -0,0 +1,32 @@
+use std::cell::Cell;
+
+use destructure::Mutation;
+
+thread_local! {
+    static CHANGED: Cell<u32> = const { Cell::new(0) };
+}
+
+fn on_price(price: &u32) {
+    CHANGED.with(|changed| changed.set(*price));
+}
+
+#[derive(Debug, PartialEq, Mutation)]
+pub struct Book {
+    name: String,
+    #[mutation(group = "pricing", on_change = "on_price")]
+    price: u32,
+    #[mutation(group = "pricing")]
+    discount: u8,
+}
+
+fn main() {
+    let mut book = Book { name: "name".to_string(), price: 1000, discount: 0 };
+
+    book.substitute_pricing(|pricing| {
+        *pricing.price = 1200;
+        *pricing.discount = 10;
+    });
+
+    assert_eq!(book, Book { name: "name".to_string(), price: 1200, discount: 10 });
+    assert_eq!(CHANGED.with(Cell::get), 1200);
+}
@@ -0,0 +1,26 @@
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use destructure::Destructure;
+
+// Not `Clone`, so the bound `T: Clone` of `#[derive(Clone)]` would not hold.
+pub struct Marker;
+
+#[derive(Destructure)]
+#[destructure(clone)]
+pub struct Repository<T> {
+    name: String,
+    shared: Rc<T>,
+    #[destructure(skip)]
+    _mark: PhantomData<T>,
+}
+
+fn main() {
+    let repository = Repository { name: "name".to_string(), shared: Rc::new(Marker), _mark: PhantomData };
+
+    let des = repository.into_destruct();
+    let cloned = des.clone();
+    assert_eq!(cloned.name, "name");
+    assert!(Rc::ptr_eq(&des.shared, &cloned.shared));
+    assert_eq!(Rc::strong_count(&des.shared), 2);
+}
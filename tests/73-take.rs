@@ -0,0 +1,24 @@
+use destructure::Destructure;
+
+#[derive(Debug, PartialEq, Destructure)]
+#[destructure(take)]
+pub struct Book {
+    name: String,
+    tags: Vec<String>,
+    // Skipped fields have no `take_<field>()`, so they need not be `Default`.
+    #[destructure(skip)]
+    isbn: Isbn,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Isbn(u64);
+
+fn main() {
+    let book = Book { name: "name".to_string(), tags: vec!["novel".to_string()], isbn: Isbn(9784000000000) };
+
+    let mut des = book.into_destruct();
+    assert_eq!(des.take_name(), "name");
+    assert_eq!(des.take_tags(), vec!["novel".to_string()]);
+
+    assert_eq!(des.freeze(), Book { name: String::new(), tags: Vec::new(), isbn: Isbn(9784000000000) });
+}
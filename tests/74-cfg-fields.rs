@@ -0,0 +1,89 @@
+use destructure::{Destructure, DestructureRef, Mutation};
+
+// `cfg(not(any()))` is always enabled and `cfg(any())` never is, standing in for a feature turned on and off.
+// `summary` follows the real `serde_json` feature, so `cargo test` with and without it builds both field sets.
+#[derive(Debug, PartialEq, Destructure, DestructureRef, Mutation)]
+pub struct Book {
+    name: String,
+    #[cfg(not(any()))]
+    pages: u32,
+    #[cfg(feature = "serde_json")]
+    summary: String,
+    #[cfg(any())]
+    preview: Vec<u8>,
+    #[cfg(any())]
+    #[destructure(skip)]
+    cache: Vec<u8>,
+}
+
+// The options building a list of the fields only see the fields which are compiled in.
+#[derive(Debug, Destructure)]
+#[destructure(from_string_map, default_fields, clone, reflect, field_enum, schema, checksum, size_hint)]
+pub struct Draft {
+    title: String,
+    #[cfg(not(any()))]
+    pages: u32,
+    #[cfg(any())]
+    note: String,
+    #[cfg(any())]
+    #[destructure(skip)]
+    cache: String,
+}
+
+#[cfg(not(any()))]
+mod enabled {
+    use destructure::Destructure;
+
+    #[derive(Destructure)]
+    pub struct Config {
+        #[cfg(not(any()))]
+        pub verbose: bool,
+        #[cfg(any())]
+        pub trace: bool,
+    }
+}
+
+fn main() {
+    let book = Book {
+        name: "name".to_string(),
+        pages: 120,
+        #[cfg(feature = "serde_json")]
+        summary: "summary".to_string(),
+    };
+
+    let view = book.as_destruct();
+    assert_eq!(*view.pages, 120);
+
+    let mut des = book.into_destruct();
+    des.pages += 1;
+    let mut book = des.freeze();
+    assert_eq!(book.pages, 121);
+    #[cfg(feature = "serde_json")]
+    assert_eq!(book.summary, "summary");
+
+    book.substitute(|book| *book.pages = 0);
+    assert_eq!(book.pages, 0);
+
+    let map = std::collections::HashMap::from([
+        ("title".to_string(), "title".to_string()),
+        ("pages".to_string(), "12".to_string()),
+    ]);
+    let draft = DestructDraft::try_from(map).unwrap();
+    assert_eq!(draft.clone().title, "title");
+    assert_eq!(DestructDraft::default().title, "");
+    assert_eq!(draft.clone().pages, 12);
+    assert_eq!(DestructDraft::default().pages, 0);
+    assert_eq!(draft.reflect().len(), 2);
+    assert_eq!(DestructDraft::schema(), vec![("title", std::any::type_name::<String>()), ("pages", "u32")]);
+    assert_eq!(draft.encoded_size_hint(), std::mem::size_of::<String>() + std::mem::size_of::<u32>());
+    assert_eq!(draft.checksum(), draft.clone().checksum());
+    let mut draft = draft;
+    draft.set("title", DestructDraftFieldValue::Title("changed".to_string())).unwrap();
+    draft.set("pages", DestructDraftFieldValue::Pages(13)).unwrap();
+    assert!(draft.set("note", DestructDraftFieldValue::Title(String::new())).is_err());
+    let draft = draft.freeze();
+    assert_eq!((draft.title.as_str(), draft.pages), ("changed", 13));
+
+    let config = enabled::Config { verbose: true }.into_destruct();
+    assert!(config.verbose);
+}
@@ -0,0 +1,25 @@
+use destructure::Destructure;
+
+#[derive(Debug, PartialEq, Destructure)]
+#[destructure(staged(required(name, author)))]
+pub struct Book {
+    name: String,
+    author: String,
+    pages: u32,
+}
+
+fn main() {
+    let book = Book { name: "name".to_string(), author: "author".to_string(), pages: 120 };
+
+    let book = book.reconstruct_staged(|mut staged| {
+        staged.destruct_mut().pages = 150;
+        staged.set_author("new author".to_string())
+            .set_name("new name".to_string())
+    });
+    assert_eq!(book, Book { name: "new name".to_string(), author: "new author".to_string(), pages: 150 });
+
+    let staged = book.into_staged().set_name("name".to_string());
+    assert_eq!(staged.destruct().author, "new author");
+    let book = staged.set_author("author".to_string()).freeze();
+    assert_eq!(book, Book { name: "name".to_string(), author: "author".to_string(), pages: 150 });
+}
@@ -0,0 +1,44 @@
+mod owned {
+    use destructure::Destructure;
+
+    #[derive(Debug, Clone, PartialEq, Destructure)]
+    #[destructure(from, borrow_view)]
+    pub struct Book {
+        pub name: String,
+        pub pages: u32,
+    }
+}
+
+mod borrowed {
+    use destructure::DestructureRef;
+
+    #[derive(DestructureRef)]
+    #[destructure(from)]
+    pub struct Author<'a, T> {
+        pub name: &'a str,
+        pub books: Vec<T>,
+    }
+}
+
+use borrowed::{Author, DestructAuthorRef};
+use owned::{Book, DestructBook, DestructBookRef};
+
+fn count<'a, V: From<&'a Book>>(book: &'a Book, f: impl FnOnce(V) -> u32) -> u32 {
+    f(book.into())
+}
+
+fn main() {
+    let book = Book { name: "name".to_string(), pages: 120 };
+
+    let des: DestructBook = book.clone().into();
+    assert_eq!(des.pages, 120);
+    let back: Book = des.into();
+    assert_eq!(back, book);
+
+    assert_eq!(count(&book, |view: DestructBookRef| *view.pages), 120);
+
+    let author = Author { name: "author", books: vec![book] };
+    let view: DestructAuthorRef<Book> = (&author).into();
+    assert_eq!(*view.name, "author");
+    assert_eq!(view.books.len(), 1);
+}
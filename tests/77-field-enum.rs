@@ -0,0 +1,42 @@
+use destructure::Destructure;
+
+#[derive(Debug, PartialEq, Destructure)]
+#[destructure(field_enum)]
+pub struct Book {
+    name: String,
+    #[destructure(rename = "page_count")]
+    pages: u32,
+    #[destructure(skip)]
+    cache: Vec<u8>,
+}
+
+// Neither the field nor the structure implements `Debug`, `Clone` or `PartialEq`.
+pub struct Handle(u8);
+
+#[derive(Destructure)]
+#[destructure(field_enum)]
+pub struct Session {
+    handle: Handle,
+}
+
+fn main() {
+    let book = Book { name: "name".to_string(), pages: 120, cache: Vec::new() };
+    let mut des = book.into_destruct();
+
+    des.set("name", DestructBookFieldValue::Name("new name".to_string())).unwrap();
+    des.set("page_count", DestructBookFieldValue::PageCount(150)).unwrap();
+
+    assert_eq!(
+        des.set("page_count", DestructBookFieldValue::Name("150".to_string())),
+        Err(DestructBookSetError::TypeMismatch { field: "page_count", expected: "u32" })
+    );
+    let error = des.set("cache", DestructBookFieldValue::PageCount(0)).unwrap_err();
+    assert_eq!(error, DestructBookSetError::UnknownField("cache".to_string()));
+    assert_eq!(error.to_string(), "unknown field `cache`");
+
+    assert_eq!(des.freeze(), Book { name: "new name".to_string(), pages: 150, cache: Vec::new() });
+
+    let mut des = Session { handle: Handle(1) }.into_destruct();
+    des.set("handle", DestructSessionFieldValue::Handle(Handle(2))).unwrap();
+    assert_eq!(des.freeze().handle.0, 2);
+}
@@ -0,0 +1,28 @@
+use destructure::Destructure;
+
+#[derive(Debug, PartialEq, Destructure)]
+#[destructure(json)]
+pub struct Book {
+    id: String,
+    #[destructure(group = "meta", serde)]
+    author: String,
+    #[destructure(group = "meta")]
+    published_at: u64,
+    #[destructure(group = "stats")]
+    pages: u32,
+}
+
+fn main() {
+    let book = Book { id: "id".to_string(), author: "author".to_string(), published_at: 1, pages: 120 };
+
+    let value = book.into_destruct().to_json_value();
+    assert_eq!(value["id"], "id");
+    assert_eq!(value["author"], "author");
+    assert_eq!(value["published_at"], 1);
+    assert_eq!(value["stats"]["pages"], 120);
+    assert!(value.get("meta").is_none());
+
+    let des = DestructBook::from_json_value(value).unwrap();
+    assert_eq!(des.meta.author, "author");
+    assert_eq!(des.freeze(), Book { id: "id".to_string(), author: "author".to_string(), published_at: 1, pages: 120 });
+}
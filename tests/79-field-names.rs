@@ -0,0 +1,33 @@
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(field_names)]
+pub struct Book {
+    name: String,
+    #[destructure(rename = "page_count")]
+    pages: u32,
+    #[destructure(skip)]
+    cache: Vec<u8>,
+}
+
+struct Buffer<const N: usize> {
+    cells: [Option<String>; N],
+}
+
+impl<const N: usize> Buffer<N> {
+    fn new() -> Self {
+        Self { cells: std::array::from_fn(|_| None) }
+    }
+}
+
+fn main() {
+    assert_eq!(DestructBook::FIELD_COUNT, 2);
+    assert_eq!(DestructBook::FIELD_NAMES, ["name", "page_count"]);
+
+    let mut buffer: Buffer<{ DestructBook::FIELD_COUNT }> = Buffer::new();
+    let values: [String; DestructBook::FIELD_COUNT] = ["name".to_string(), "120".to_string()];
+    for (cell, value) in buffer.cells.iter_mut().zip(values) {
+        *cell = Some(value);
+    }
+    assert_eq!(buffer.cells.len(), DestructBook::FIELD_NAMES.len());
+}
@@ -0,0 +1,37 @@
+use destructure::DestructureRef;
+
+#[derive(DestructureRef)]
+#[destructure(merge_from_ref)]
+pub struct Book {
+    name: String,
+    #[destructure(copy)]
+    pages: u32,
+    #[destructure(copy)]
+    published_at: u64,
+}
+
+#[derive(DestructureRef)]
+pub struct Point {
+    #[destructure(copy)]
+    x: i32,
+    #[destructure(copy)]
+    y: i32,
+}
+
+fn main() {
+    let book = Book { name: "name".to_string(), pages: 120, published_at: 1 };
+
+    let view = book.as_destruct();
+    let pages: u32 = view.pages;
+    assert_eq!(pages + 1, 121);
+    assert_eq!(view.published_at, 1);
+    assert_eq!(view.name, "name");
+
+    let mut other = Book { name: String::new(), pages: 0, published_at: 0 };
+    other.merge_from_ref(&view);
+    assert_eq!((other.name.as_str(), other.pages, other.published_at), ("name", 120, 1));
+
+    let point = Point { x: 1, y: 2 };
+    let DestructPointRef { x, y, .. } = point.as_destruct();
+    assert_eq!(x + y, 3);
+}
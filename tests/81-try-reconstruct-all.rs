@@ -0,0 +1,53 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+pub struct Book {
+    id: String,
+    name: String,
+    published_at: String,
+    author: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BookError {
+    EmptyName,
+    EmptyAuthor,
+}
+
+#[allow(unused)]
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        published_at: "2023/01/03".to_string(),
+        author: "author".to_string(),
+    };
+
+    let errors = book.try_reconstruct_all(|des, errors| {
+        des.name = String::new();
+        des.author = String::new();
+        if des.name.is_empty() {
+            errors.push(BookError::EmptyName);
+        }
+        if des.author.is_empty() {
+            errors.push(BookError::EmptyAuthor);
+        }
+    }).unwrap_err();
+
+    assert_eq!(errors, vec![BookError::EmptyName, BookError::EmptyAuthor]);
+
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        published_at: "2023/01/03".to_string(),
+        author: "author".to_string(),
+    };
+
+    let book = book.try_reconstruct_all(|des, _: &mut Vec<BookError>| {
+        des.name = "new name".to_string();
+    }).unwrap();
+
+    assert_eq!(book.name, "new name");
+}
@@ -0,0 +1,40 @@
+#![allow(dead_code)]
+
+mod book {
+    use destructure::Destructure;
+
+    #[derive(Debug, Destructure)]
+    #[destructure(field_visibility = "pub(crate)")]
+    pub struct Book {
+        id: String,
+        name: String,
+        #[destructure(group = "meta")]
+        published_at: String,
+        #[destructure(group = "meta")]
+        author: String,
+    }
+
+    impl Book {
+        pub fn new(id: &str, name: &str) -> Self {
+            Self {
+                id: id.to_string(),
+                name: name.to_string(),
+                published_at: "2023/01/03".to_string(),
+                author: "author".to_string(),
+            }
+        }
+    }
+}
+
+fn main() {
+    let book = book::Book::new("123456789-abc", "name");
+
+    let mut des: book::DestructBook = book.into_destruct();
+    des.name = "new name".to_string();
+    des.meta.author = "reirokusanami".to_string();
+    let des = des.freeze().into_destruct();
+
+    assert_eq!(des.id, "123456789-abc");
+    assert_eq!(des.name, "new name");
+    assert_eq!(des.meta.author, "reirokusanami");
+}
@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Default, Destructure)]
+#[destructure(replace_with)]
+pub struct Book {
+    id: String,
+    name: String,
+    published_at: String,
+    author: String,
+}
+
+pub struct Library {
+    book: Book,
+}
+
+#[allow(unused)]
+fn main() {
+    let mut library = Library {
+        book: Book {
+            id: "123456789-abc".to_string(),
+            name: "name".to_string(),
+            published_at: "2023/01/03".to_string(),
+            author: "author".to_string(),
+        },
+    };
+
+    library.book.replace_with(|des| DestructBook {
+        name: "new name".to_string(),
+        ..des
+    });
+
+    assert_eq!(library.book.id, "123456789-abc");
+    assert_eq!(library.book.name, "new name");
+
+    std::panic::set_hook(Box::new(|_| {}));
+    let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        library.book.replace_with(|_| panic!("aborted"));
+    }));
+
+    assert!(panicked.is_err());
+    assert_eq!(library.book.id, "");
+}
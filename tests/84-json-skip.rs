@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(json)]
+pub struct Book {
+    id: String,
+    name: String,
+    #[destructure(skip)]
+    revision: u32,
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        revision: 3,
+    };
+
+    let value = book.into_destruct().to_json_value();
+    assert_eq!(value["name"], "name");
+    assert!(value.get("revision").is_none());
+
+    let book = DestructBook::from_json_value(value).unwrap().freeze();
+    assert_eq!(book.id, "123456789-abc");
+    assert_eq!(book.revision, 0);
+}
@@ -0,0 +1,40 @@
+#![allow(dead_code)]
+
+use std::collections::HashSet;
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+pub struct Book {
+    #[destructure(id)]
+    id: String,
+    name: String,
+    published_at: String,
+}
+
+#[derive(Debug, Destructure)]
+pub struct Chapter {
+    #[destructure(id)]
+    book_id: String,
+    #[destructure(id)]
+    number: u32,
+    title: String,
+}
+
+fn main() {
+    let a = Book { id: "123456789-abc".to_string(), name: "name".to_string(), published_at: "2023/01/03".to_string() }.into_destruct();
+    let b = Book { id: "123456789-abc".to_string(), name: "other".to_string(), published_at: "2023/01/04".to_string() }.into_destruct();
+    let c = Book { id: "987654321-xyz".to_string(), name: "name".to_string(), published_at: "2023/01/03".to_string() }.into_destruct();
+
+    assert!(a == b);
+    assert!(a != c);
+
+    let set = [a, b, c].into_iter().collect::<HashSet<_>>();
+    assert_eq!(set.len(), 2);
+
+    let first = Chapter { book_id: "123456789-abc".to_string(), number: 1, title: "first".to_string() }.into_destruct();
+    let renamed = Chapter { book_id: "123456789-abc".to_string(), number: 1, title: "renamed".to_string() }.into_destruct();
+    let second = Chapter { book_id: "123456789-abc".to_string(), number: 2, title: "first".to_string() }.into_destruct();
+
+    assert!(first == renamed);
+    assert!(first != second);
+}
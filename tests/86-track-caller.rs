@@ -0,0 +1,33 @@
+#![allow(dead_code)]
+
+use std::sync::Mutex;
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(assert = "self.age < 150")]
+pub struct Person {
+    name: String,
+    age: u32,
+}
+
+static LOCATION: Mutex<Option<(String, u32)>> = Mutex::new(None);
+
+fn main() {
+    std::panic::set_hook(Box::new(|info| {
+        let location = info.location().unwrap();
+        *LOCATION.lock().unwrap() = Some((location.file().to_string(), location.line()));
+    }));
+
+    let person = Person { name: "name".to_string(), age: 20 };
+    let line = line!() + 2;
+    let violated = std::panic::catch_unwind(|| {
+        person.reconstruct(|before| before.age = 200)
+    });
+
+    if cfg!(debug_assertions) {
+        assert!(violated.is_err());
+        let (file, reported) = LOCATION.lock().unwrap().clone().unwrap();
+        assert!(file.ends_with("86-track-caller.rs"));
+        assert_eq!(reported, line);
+    }
+}
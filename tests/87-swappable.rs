@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(swappable)]
+pub struct Book {
+    id: String,
+    #[destructure(rename = "title")]
+    name: String,
+    #[destructure(group = "meta")]
+    author: String,
+    #[destructure(skip)]
+    revision: u32,
+}
+
+fn main() {
+    let mut a = Book { id: "a".to_string(), name: "first".to_string(), author: "alice".to_string(), revision: 1 };
+    let mut b = Book { id: "b".to_string(), name: "second".to_string(), author: "bob".to_string(), revision: 2 };
+
+    a.swap_name_with(&mut b);
+    assert_eq!((a.id.as_str(), a.name.as_str()), ("a", "second"));
+    assert_eq!((b.id.as_str(), b.name.as_str()), ("b", "first"));
+
+    a.swap_author_with(&mut b);
+    assert_eq!((a.author.as_str(), b.author.as_str()), ("bob", "alice"));
+    assert_eq!((a.revision, b.revision), (1, 2));
+}
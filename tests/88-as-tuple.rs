@@ -0,0 +1,38 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(as_tuple)]
+pub struct Book<T> {
+    id: String,
+    #[destructure(rename = "title")]
+    name: String,
+    content: T,
+    #[destructure(skip)]
+    revision: u32,
+}
+
+fn publish(id: String, title: String, content: Vec<u8>) -> (String, String, Vec<u8>) {
+    (id, title.to_uppercase(), content)
+}
+
+fn main() {
+    let book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        content: vec![1u8, 2, 3],
+        revision: 3,
+    };
+
+    let (id, title, content) = book.into_destruct().into_tuple();
+    assert_eq!((id.as_str(), title.as_str()), ("123456789-abc", "name"));
+
+    let book = DestructBook::from_tuple(publish(id, title, content)).freeze();
+    assert_eq!(book.name, "NAME");
+    assert_eq!(book.content, vec![1, 2, 3]);
+    assert_eq!(book.revision, 0);
+
+    let (id, title, content): (String, String, Vec<u8>) = book.into_destruct().into();
+    assert_eq!((id.as_str(), title.as_str(), content.len()), ("123456789-abc", "NAME", 3));
+}
@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(into_iter)]
+pub struct Scores {
+    math: u32,
+    science: u32,
+    #[destructure(skip)]
+    revision: u32,
+}
+
+#[derive(Debug, Destructure)]
+#[destructure(into_iter)]
+pub struct Pair<T> {
+    left: T,
+    right: T,
+}
+
+fn main() {
+    let des = Scores { math: 80, science: 65, revision: 1 }.into_destruct();
+
+    let mut total = 0;
+    for score in &des {
+        total += *score;
+    }
+    assert_eq!(total, 145);
+    assert_eq!((&des).into_iter().max(), Some(&80));
+    assert_eq!(des.freeze().math, 80);
+
+    let pair = Pair { left: "left".to_string(), right: "right".to_string() }.into_destruct();
+    let joined = (&pair).into_iter().map(String::as_str).collect::<Vec<_>>().join("-");
+    assert_eq!(joined, "left-right");
+    assert_eq!(pair.into_iter().count(), 2);
+}
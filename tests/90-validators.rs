@@ -0,0 +1,43 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+mod validation {
+    pub fn not_empty(value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            Err("must not be empty".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn adult(age: &u32) -> Result<(), String> {
+        if *age < 18 {
+            Err(format!("{} is under 18", age))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug, Destructure)]
+#[destructure(validators)]
+pub struct SignUp {
+    #[destructure(validate = "validation::not_empty", rename = "username")]
+    name: String,
+    #[destructure(validate = "validation::adult")]
+    age: u32,
+    newsletter: bool,
+}
+
+fn main() {
+    let mut form = SignUp { name: "name".to_string(), age: 20, newsletter: true }.into_destruct();
+    assert_eq!(form.validate_all(), vec![("username", Ok(())), ("age", Ok(()))]);
+
+    form.username = String::new();
+    form.age = 12;
+    assert_eq!(form.validate_all(), vec![
+        ("username", Err("must not be empty".to_string())),
+        ("age", Err("12 is under 18".to_string())),
+    ]);
+}
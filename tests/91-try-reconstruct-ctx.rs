@@ -0,0 +1,76 @@
+#![allow(dead_code)]
+
+use std::error::Error;
+use destructure::Destructure;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Author(String);
+
+impl From<Author> for String {
+    fn from(author: Author) -> Self {
+        author.0
+    }
+}
+
+impl TryFrom<String> for Author {
+    type Error = BookError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        if name.is_empty() {
+            Err(BookError::EmptyAuthor)
+        } else {
+            Ok(Self(name))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BookError {
+    EmptyName,
+    EmptyAuthor,
+}
+
+impl std::fmt::Display for BookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyName => write!(f, "name is empty"),
+            Self::EmptyAuthor => write!(f, "author is empty"),
+        }
+    }
+}
+
+impl Error for BookError {}
+
+#[derive(Debug, Destructure)]
+pub struct Book {
+    id: String,
+    name: String,
+}
+
+#[derive(Debug, Destructure)]
+pub struct Article {
+    title: String,
+    #[destructure(try_from = "String")]
+    author: Author,
+}
+
+fn main() {
+    let book = Book { id: "123456789-abc".to_string(), name: "name".to_string() };
+    let error = book.try_reconstruct_ctx(|des| {
+        des.name = String::new();
+        Err(BookError::EmptyName)
+    }).unwrap_err();
+
+    assert_eq!(error, DestructBookReconstructError { structure: "Book", source: BookError::EmptyName });
+    assert_eq!(error.to_string(), "failed to reconstruct `Book`: name is empty");
+    assert_eq!(error.source().unwrap().to_string(), "name is empty");
+
+    let article = Article { title: "title".to_string(), author: Author("author".to_string()) };
+    let error = article.try_reconstruct_ctx(|des| -> Result<(), BookError> {
+        des.author = String::new();
+        Ok(())
+    }).unwrap_err();
+
+    assert_eq!(error.structure, "Article");
+    assert_eq!(error.source, BookError::EmptyAuthor);
+}
@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(test_fixture)]
+pub struct Book<T> {
+    id: String,
+    name: String,
+    content: T,
+    #[destructure(skip)]
+    revision: u32,
+    #[destructure(skip_drop, init = "Counter(1)")]
+    counter: Counter,
+}
+
+// No `Default`, so the fixture has to use the `init` of the `skip_drop` field.
+#[derive(Debug, PartialEq)]
+pub struct Counter(u32);
+
+// The fixtures only exist in test builds, so they are exercised under `cargo test`.
+#[test]
+fn fixture() {
+    let book = Book::<Vec<u8>>::fixture();
+    assert_eq!(book.id, "");
+    assert!(book.content.is_empty());
+
+    let book = Book::<Vec<u8>>::fixture_with(|des| {
+        des.name = "name".to_string();
+        des.content = vec![1, 2, 3];
+    });
+    assert_eq!(book.name, "name");
+    assert_eq!(book.content, vec![1, 2, 3]);
+    assert_eq!(book.revision, 0);
+    assert_eq!(book.counter, Counter(1));
+}
+
+fn main() {
+    let book = Book { id: "123456789-abc".to_string(), name: "name".to_string(), content: (), revision: 0, counter: Counter(5) };
+    assert_eq!(book.into_destruct().id, "123456789-abc");
+}
@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+
+mod book {
+    use destructure::{Destructure, DestructureRef};
+    use serde::Serialize;
+
+    #[derive(Debug, Serialize, Destructure)]
+    #[destructure(respect_serde_skip, field_names)]
+    pub struct Book {
+        pub id: String,
+        pub name: String,
+        #[serde(skip)]
+        pub revision: u32,
+        #[serde(rename = "cached", skip_serializing)]
+        pub cache: Vec<u8>,
+    }
+
+    #[derive(Debug, Serialize, DestructureRef)]
+    #[destructure(respect_serde_skip)]
+    pub struct User {
+        pub name: String,
+        #[serde(skip)]
+        pub password: String,
+    }
+
+    // The skipped field stays on the view, but is private to this module.
+    pub fn password_len(user: &User) -> usize {
+        user.as_destruct().password.len()
+    }
+}
+
+fn main() {
+    let book = book::Book { id: "123456789-abc".to_string(), name: "name".to_string(), revision: 3, cache: vec![1] };
+
+    assert_eq!(book::DestructBook::FIELD_NAMES, ["id", "name"]);
+
+    let mut des = book.into_destruct();
+    des.name = "new name".to_string();
+    let book = des.freeze();
+    assert_eq!((book.name.as_str(), book.revision, book.cache.len()), ("new name", 3, 1));
+
+    let user = book::User { name: "name".to_string(), password: "secret".to_string() };
+    assert_eq!(user.as_destruct().name, "name");
+    assert_eq!(book::password_len(&user), 6);
+}
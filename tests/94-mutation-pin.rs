@@ -0,0 +1,43 @@
+#![allow(dead_code)]
+
+use std::marker::PhantomPinned;
+use std::pin::{Pin, pin};
+use destructure::Mutation;
+
+#[derive(Debug, Default)]
+pub struct Ticker {
+    ticks: u32,
+    _pinned: PhantomPinned,
+}
+
+impl Ticker {
+    pub fn tick(self: Pin<&mut Self>) {
+        // SAFETY: `ticks` is not structurally pinned.
+        unsafe { self.get_unchecked_mut().ticks += 1 }
+    }
+}
+
+#[derive(Debug, Mutation)]
+#[mutation(pin)]
+pub struct Clock {
+    #[mutation(pinned)]
+    ticker: Ticker,
+    label: String,
+}
+
+fn assert_unpin<T: Unpin>() {}
+
+fn main() {
+    let mut clock = pin!(Clock { ticker: Ticker::default(), label: "clock".to_string() });
+
+    clock.as_mut().substitute_pinned(|clock| {
+        clock.ticker.as_mut().tick();
+        clock.ticker.as_mut().tick();
+        *clock.label = "ticked".to_string();
+    });
+
+    assert_eq!(clock.ticker.ticks, 2);
+    assert_eq!(clock.label, "ticked");
+
+    assert_unpin::<ClockPinMut<'static>>();
+}
@@ -0,0 +1,32 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(clone)]
+pub struct Document<T> {
+    name: String,
+    #[destructure(deep_clone)]
+    body: Arc<Vec<T>>,
+    #[destructure(deep_clone)]
+    title: std::rc::Rc<String>,
+    shared: Rc<String>,
+}
+
+fn main() {
+    let document = Document {
+        name: "name".to_string(),
+        body: Arc::new(vec![1u8, 2, 3]),
+        title: Rc::new("title".to_string()),
+        shared: Rc::new("shared".to_string()),
+    };
+
+    let des = document.into_destruct();
+    let cloned = des.clone();
+    assert_eq!(*cloned.body, vec![1, 2, 3]);
+    assert!(!Arc::ptr_eq(&des.body, &cloned.body));
+    assert_eq!(Arc::strong_count(&des.body), 1);
+    assert!(!Rc::ptr_eq(&des.title, &cloned.title));
+    assert!(Rc::ptr_eq(&des.shared, &cloned.shared));
+}
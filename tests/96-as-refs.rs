@@ -0,0 +1,33 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(as_refs)]
+pub struct Book<T> {
+    id: String,
+    #[destructure(rename = "title")]
+    name: String,
+    content: T,
+    #[destructure(skip)]
+    revision: u32,
+}
+
+fn describe<A: std::fmt::Display, B: std::fmt::Display>((a, b, _): (&A, &B, &Vec<u8>)) -> String {
+    format!("{}: {}", a, b)
+}
+
+fn main() {
+    let des = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        content: vec![1u8, 2, 3],
+        revision: 3,
+    }.into_destruct();
+
+    let (id, title, content) = des.as_refs();
+    assert_eq!((id.as_str(), title.as_str(), content.len()), ("123456789-abc", "name", 3));
+    assert_eq!(describe(des.as_refs()), "123456789-abc: name");
+
+    assert_eq!(des.freeze().revision, 3);
+}
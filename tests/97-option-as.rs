@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(into_string_map, from_string_map, from_str)]
+pub struct Book {
+    id: String,
+    pages: u32,
+    subtitle: Option<String>,
+}
+
+#[derive(Debug, Destructure)]
+#[destructure(into_string_map, from_string_map, option_as = "empty")]
+pub struct Article {
+    id: String,
+    subtitle: Option<String>,
+}
+
+#[derive(Debug, Destructure)]
+#[destructure(into_string_map, from_string_map, from_str, option_as = "null")]
+pub struct Report {
+    id: String,
+    #[destructure(skip)]
+    revision: u32,
+    reviewed_at: Option<u64>,
+}
+
+fn main() {
+    let map = Book { id: "1".to_string(), pages: 42, subtitle: None }.into_destruct().into_string_map();
+    assert_eq!(map, HashMap::from([("id".to_string(), "1".to_string()), ("pages".to_string(), "42".to_string())]));
+    let mut des = DestructBook::try_from(map).unwrap();
+    assert_eq!(des.subtitle, None);
+    des.set_from_str("subtitle", "sub").unwrap();
+    assert_eq!(des.subtitle, Some("sub".to_string()));
+    assert_eq!(des.into_string_map()["subtitle"], "sub");
+
+    let map = Article { id: "2".to_string(), subtitle: None }.into_destruct().into_string_map();
+    assert_eq!(map["subtitle"], "");
+    assert_eq!(DestructArticle::try_from(map).unwrap().subtitle, None);
+    let missing = DestructArticle::try_from(HashMap::from([("id".to_string(), "2".to_string())]));
+    assert_eq!(missing.err(), Some(DestructArticleParseError::MissingField("subtitle")));
+
+    let map = Report { id: "3".to_string(), revision: 1, reviewed_at: None }.into_destruct().into_string_map();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["reviewed_at"], "null");
+    let mut des = DestructReport::try_from(map).unwrap();
+    assert_eq!(des.reviewed_at, None);
+    des.set_from_str("reviewed_at", "1700000000").unwrap();
+    assert_eq!(des.reviewed_at, Some(1700000000));
+    des.set_from_str("reviewed_at", "null").unwrap();
+    assert_eq!(des.reviewed_at, None);
+}
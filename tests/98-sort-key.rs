@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+pub struct Book {
+    id: String,
+    name: String,
+    #[destructure(sort_key)]
+    created_at: u64,
+}
+
+fn book(id: &str, created_at: u64) -> DestructBook {
+    Book { id: id.to_string(), name: "name".to_string(), created_at }.into_destruct()
+}
+
+fn main() {
+    let mut books = [book("c", 30), book("a", 10), book("b", 20)];
+    books.sort();
+    assert_eq!(books.iter().map(|des| des.id.as_str()).collect::<Vec<_>>(), vec!["a", "b", "c"]);
+
+    assert!(book("x", 1) == book("y", 1));
+    assert!(book("x", 1) < book("x", 2));
+    assert_eq!(books.iter().max().unwrap().created_at, 30);
+}
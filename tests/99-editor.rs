@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+
+use destructure::Destructure;
+
+#[derive(Debug, Destructure)]
+#[destructure(editor)]
+pub struct Book<T> {
+    id: String,
+    #[destructure(rename = "title")]
+    name: String,
+    content: T,
+    #[destructure(skip)]
+    revision: u32,
+}
+
+fn retitle<T>(editor: &mut BookEditor<'_, T>, title: &str) {
+    editor.set_title(title.to_string());
+}
+
+fn main() {
+    let mut book = Book {
+        id: "123456789-abc".to_string(),
+        name: "name".to_string(),
+        content: vec![1u8, 2, 3],
+        revision: 3,
+    };
+
+    let mut editor = book.edit();
+    retitle(&mut editor, "new name");
+    editor.set_content(vec![4, 5]);
+    editor.commit();
+
+    assert_eq!(book.name, "new name");
+    assert_eq!(book.content, vec![4, 5]);
+    assert_eq!((book.id.as_str(), book.revision), ("123456789-abc", 3));
+
+    let mut editor = book.edit();
+    editor.set_title("discarded".to_string());
+    drop(editor);
+    assert_eq!(book.name, "new name");
+
+    book.edit().set_title("chained".to_string()).set_content(vec![6]).commit();
+    assert_eq!((book.name.as_str(), book.content.as_slice()), ("chained", &[6][..]));
+
+    let mut editor = book.edit();
+    editor.set_title("committed".to_string()).commit();
+    editor.commit();
+    assert_eq!(book.name, "committed");
+}
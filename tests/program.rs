@@ -10,4 +10,144 @@ fn tests() {
     try_test.pass("tests/07-mutation.rs");
     try_test.pass("tests/08-try-mutation.rs");
     try_test.pass("tests/09-multiple-generics.rs");
+    try_test.pass("tests/10-destructure-ref.rs");
+    try_test.pass("tests/11-skip.rs");
+    try_test.pass("tests/12-rename.rs");
+    try_test.pass("tests/13-methods.rs");
+    try_test.pass("tests/14-lifetime-type-mix.rs");
+    try_test.pass("tests/15-from-str.rs");
+    try_test.pass("tests/16-bare-attribute.rs");
+    try_test.pass("tests/17-into-iter.rs");
+    try_test.pass("tests/18-skip-destructure-ref.rs");
+    try_test.pass("tests/19-fold.rs");
+    try_test.pass("tests/20-skip-if.rs");
+    try_test.pass("tests/21-mutation-lifetime.rs");
+    try_test.pass("tests/22-bound-relaxed.rs");
+    try_test.pass("tests/23-as-mut-view.rs");
+    try_test.pass("tests/24-debug-redact.rs");
+    try_test.pass("tests/25-size-hint.rs");
+    try_test.pass("tests/26-transparent.rs");
+    try_test.pass("tests/27-edit.rs");
+    try_test.pass("tests/28-packed.rs");
+    try_test.pass("tests/29-map-all.rs");
+    try_test.pass("tests/30-try-from.rs");
+    try_test.pass("tests/31-eq-skip.rs");
+    try_test.pass("tests/32-reconstruct-tracked.rs");
+    try_test.pass("tests/33-boxed.rs");
+    try_test.pass("tests/34-group.rs");
+    try_test.pass("tests/35-borrow-view.rs");
+    try_test.pass("tests/36-nested-scope.rs");
+    try_test.pass("tests/37-reconstruct-with-ctx.rs");
+    try_test.pass("tests/38-display.rs");
+    #[cfg(feature = "serde_json")]
+    try_test.pass("tests/39-json.rs");
+    try_test.pass("tests/40-schema.rs");
+    try_test.pass("tests/41-skip-drop.rs");
+    try_test.pass("tests/42-merge-from-ref.rs");
+    try_test.pass("tests/43-order.rs");
+    try_test.pass("tests/44-from-string-map.rs");
+    try_test.pass("tests/45-phantom.rs");
+    try_test.pass("tests/46-async-try-substitute.rs");
+    try_test.pass("tests/47-trait-bound-generics.rs");
+    try_test.pass("tests/48-freeze-assert.rs");
+    try_test.pass("tests/49-cow-accessor.rs");
+    try_test.pass("tests/50-unsafe-set.rs");
+    try_test.pass("tests/51-adapt.rs");
+    try_test.pass("tests/52-by-ref-clone.rs");
+    try_test.pass("tests/53-on-change.rs");
+    try_test.pass("tests/54-rename-consistency.rs");
+    try_test.pass("tests/55-with-defaults.rs");
+    try_test.pass("tests/56-const-generic-array.rs");
+    try_test.pass("tests/57-index-usize.rs");
+    try_test.pass("tests/58-private-fields.rs");
+    try_test.pass("tests/59-reconstruct-option.rs");
+    try_test.pass("tests/60-sealed.rs");
+    try_test.pass("tests/61-collection.rs");
+    try_test.pass("tests/62-ref-lifetime.rs");
+    try_test.pass("tests/63-mutation-array.rs");
+    try_test.pass("tests/64-checksum.rs");
+    try_test.pass("tests/65-generic-field.rs");
+    try_test.pass("tests/66-default-fields.rs");
+    try_test.pass("tests/67-freeze-via.rs");
+    try_test.pass("tests/68-diff-patch.rs");
+    try_test.pass("tests/69-self-referential.rs");
+    try_test.pass("tests/70-reflect.rs");
+    try_test.pass("tests/71-mutation-group.rs");
+    try_test.pass("tests/72-clone.rs");
+    try_test.pass("tests/73-take.rs");
+    try_test.pass("tests/74-cfg-fields.rs");
+    try_test.pass("tests/75-staged.rs");
+    try_test.pass("tests/76-from.rs");
+    try_test.pass("tests/77-field-enum.rs");
+    #[cfg(feature = "serde_json")]
+    try_test.pass("tests/78-json-group.rs");
+    try_test.pass("tests/79-field-names.rs");
+    try_test.pass("tests/80-ref-copy.rs");
+    try_test.pass("tests/81-try-reconstruct-all.rs");
+    try_test.pass("tests/82-field-visibility.rs");
+    try_test.pass("tests/83-replace-with.rs");
+    #[cfg(feature = "serde_json")]
+    try_test.pass("tests/84-json-skip.rs");
+    try_test.pass("tests/85-id.rs");
+    try_test.pass("tests/86-track-caller.rs");
+    try_test.pass("tests/87-swappable.rs");
+    try_test.pass("tests/88-as-tuple.rs");
+    try_test.pass("tests/89-into-iter-ref.rs");
+    try_test.pass("tests/90-validators.rs");
+    try_test.pass("tests/91-try-reconstruct-ctx.rs");
+    try_test.pass("tests/92-test-fixture.rs");
+    try_test.pass("tests/93-respect-serde-skip.rs");
+    try_test.pass("tests/94-mutation-pin.rs");
+    try_test.pass("tests/95-deep-clone.rs");
+    try_test.pass("tests/96-as-refs.rs");
+    try_test.pass("tests/97-option-as.rs");
+    try_test.pass("tests/98-sort-key.rs");
+    try_test.pass("tests/99-editor.rs");
+    try_test.pass("tests/100-doc-hidden.rs");
+    try_test.pass("tests/101-build-error.rs");
+    #[cfg(feature = "arbitrary")]
+    try_test.pass("tests/102-arbitrary.rs");
+    #[cfg(feature = "zeroize")]
+    try_test.pass("tests/103-zeroize.rs");
+    try_test.compile_fail("tests/ui/rename-keyword.rs");
+    try_test.compile_fail("tests/ui/methods-absent.rs");
+    try_test.compile_fail("tests/ui/methods-unknown.rs");
+    try_test.compile_fail("tests/ui/into-iter-mixed.rs");
+    try_test.compile_fail("tests/ui/skip-private.rs");
+    try_test.compile_fail("tests/ui/rename-duplicate.rs");
+    try_test.compile_fail("tests/ui/unknown-attribute.rs");
+    try_test.compile_fail("tests/ui/skip-if-without-path.rs");
+    try_test.compile_fail("tests/ui/transparent-multiple.rs");
+    try_test.compile_fail("tests/ui/packed-ref.rs");
+    try_test.compile_fail("tests/ui/map-all-mixed.rs");
+    try_test.compile_fail("tests/ui/display-unknown-field.rs");
+    try_test.compile_fail("tests/ui/skip-drop-without-init.rs");
+    try_test.compile_fail("tests/ui/order-duplicate.rs");
+    try_test.compile_fail("tests/ui/adapt-unknown-field.rs");
+    try_test.compile_fail("tests/ui/sealed-literal.rs");
+    try_test.compile_fail("tests/ui/collection-unsupported.rs");
+    try_test.compile_fail("tests/ui/ref-lifetime-collision.rs");
+    try_test.compile_fail("tests/ui/mutation-array-mixed.rs");
+    try_test.compile_fail("tests/ui/deprecated-field.rs");
+    try_test.compile_fail("tests/ui/generic-field-shared.rs");
+    try_test.compile_fail("tests/ui/mutation-group-outside.rs");
+    try_test.compile_fail("tests/ui/adapt-skipped-field.rs");
+    try_test.compile_fail("tests/ui/staged-unset.rs");
+    try_test.compile_fail("tests/ui/group-serde-without-json.rs");
+    try_test.compile_fail("tests/ui/id-eq-skip.rs");
+    try_test.compile_fail("tests/ui/validate-without-validators.rs");
+    try_test.compile_fail("tests/ui/mutation-pin-drop.rs");
+    try_test.compile_fail("tests/ui/mutation-pin-unpin.rs");
+    try_test.compile_fail("tests/ui/deep-clone-not-shared.rs");
+    try_test.compile_fail("tests/ui/option-as-unknown.rs");
+    try_test.compile_fail("tests/ui/sort-key-multiple.rs");
+    try_test.compile_fail("tests/ui/build-error-from.rs");
+    try_test.compile_fail("tests/ui/field-visibility-invalid.rs");
+    try_test.compile_fail("tests/ui/respect-serde-skip-ref.rs");
+    try_test.compile_fail("tests/ui/mutation-array-cfg.rs");
+    try_test.compile_fail("tests/ui/ref-unused-option.rs");
+    try_test.compile_fail("tests/ui/merge-from-ref-without-borrow-view.rs");
+    try_test.compile_fail("tests/ui/transparent-unused-option.rs");
+    #[cfg(feature = "zeroize")]
+    try_test.compile_fail("tests/ui/zeroize-without-default.rs");
 }
\ No newline at end of file
@@ -0,0 +1,14 @@
+use destructure::Destructure;
+
+pub struct Dto {
+    pub identifier: String,
+}
+
+#[derive(Destructure)]
+#[destructure(adapt(target = "Dto", map(id = "identifier")))]
+pub struct Book {
+    #[destructure(skip)]
+    id: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use destructure::Destructure;
+
+pub struct Dto {
+    pub identifier: String,
+}
+
+#[derive(Destructure)]
+#[destructure(adapt(target = "Dto", map(key = "identifier")))]
+pub struct Book {
+    id: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(build_error, from)]
+pub struct Book {
+    id: String,
+    #[destructure(try_from = "String")]
+    author: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+pub struct Book {
+    #[destructure(collection)]
+    tags: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(clone)]
+pub struct Document {
+    #[destructure(deep_clone)]
+    body: Box<String>,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![deny(deprecated)]
+
+use destructure::Destructure;
+
+#[derive(Destructure)]
+pub struct Book {
+    name: String,
+    #[deprecated(note = "use `name` instead")]
+    title: String,
+}
+
+fn main() {
+    #[allow(deprecated)]
+    let book = Book { name: "name".to_string(), title: "title".to_string() };
+    let des = book.into_destruct();
+    let _ = des.title;
+}
@@ -0,0 +1,10 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(display = "{name} by {author}")]
+pub struct Book {
+    id: String,
+    name: String,
+}
+
+fn main() {}
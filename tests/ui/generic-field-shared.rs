@@ -0,0 +1,10 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+pub struct Wrapper<T> {
+    #[destructure(generic_field)]
+    value: T,
+    history: Vec<T>,
+}
+
+fn main() {}
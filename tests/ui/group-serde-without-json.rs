@@ -0,0 +1,10 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+pub struct Book {
+    id: String,
+    #[destructure(group = "meta", serde)]
+    author: String,
+}
+
+fn main() {}
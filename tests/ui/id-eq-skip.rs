@@ -0,0 +1,12 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+pub struct Book {
+    #[destructure(id)]
+    id: String,
+    name: String,
+    #[destructure(eq_skip)]
+    published_at: String,
+}
+
+fn main() {}
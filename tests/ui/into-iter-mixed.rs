@@ -0,0 +1,10 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(into_iter)]
+pub struct Book {
+    id: u64,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(map_all)]
+pub struct Point<T> {
+    x: T,
+    y: f32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(merge_from_ref)]
+pub struct Book {
+    id: String,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(methods(try_reconstruct))]
+pub struct Book {
+    id: String,
+    name: String,
+}
+
+fn main() {
+    let book = Book { id: "123456789-abc".to_string(), name: "name".to_string() };
+    let _ = book.reconstruct(|des| {
+        des.name = "new name".to_string();
+    });
+}
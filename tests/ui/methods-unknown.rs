@@ -0,0 +1,10 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(methods(reconstruct, substitute))]
+pub struct Book {
+    id: String,
+    name: String,
+}
+
+fn main() {}
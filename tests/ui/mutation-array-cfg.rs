@@ -0,0 +1,12 @@
+use destructure::Mutation;
+
+#[derive(Mutation)]
+#[mutation(array)]
+pub struct Point {
+    x: f32,
+    y: f32,
+    #[cfg(not(any()))]
+    z: f32,
+}
+
+fn main() {}
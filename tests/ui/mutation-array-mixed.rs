@@ -0,0 +1,10 @@
+use destructure::Mutation;
+
+#[derive(Mutation)]
+#[mutation(array)]
+pub struct Book {
+    id: u32,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use destructure::Mutation;
+
+#[derive(Mutation)]
+pub struct Book {
+    name: String,
+    #[mutation(group = "pricing")]
+    price: u32,
+}
+
+fn main() {
+    let mut book = Book { name: "name".to_string(), price: 1000 };
+
+    book.substitute_pricing(|pricing| {
+        *pricing.name = "new name".to_string();
+    });
+}
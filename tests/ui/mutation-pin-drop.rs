@@ -0,0 +1,16 @@
+use std::marker::PhantomPinned;
+use destructure::Mutation;
+
+#[derive(Mutation)]
+#[mutation(pin)]
+pub struct Clock {
+    #[mutation(pinned)]
+    pinned: PhantomPinned,
+    label: String,
+}
+
+impl Drop for Clock {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use std::marker::PhantomPinned;
+use destructure::Mutation;
+
+#[derive(Mutation)]
+#[mutation(pin)]
+pub struct Clock {
+    #[mutation(pinned)]
+    pinned: PhantomPinned,
+    label: String,
+}
+
+fn assert_unpin<T: Unpin>() {}
+
+fn main() {
+    assert_unpin::<Clock>();
+}
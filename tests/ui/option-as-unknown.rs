@@ -0,0 +1,10 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(into_string_map, option_as = "none")]
+pub struct Book {
+    id: String,
+    subtitle: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+pub struct Book {
+    #[destructure(order = 1)]
+    id: String,
+    #[destructure(order = 1)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use destructure::{DestructureRef, Mutation};
+
+#[derive(DestructureRef)]
+#[repr(packed)]
+pub struct Header {
+    kind: u8,
+    length: u32,
+}
+
+#[derive(Mutation)]
+#[repr(C, packed(2))]
+pub struct Footer {
+    kind: u8,
+    length: u32,
+}
+
+fn main() {}
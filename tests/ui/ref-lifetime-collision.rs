@@ -0,0 +1,9 @@
+use destructure::DestructureRef;
+
+#[derive(DestructureRef)]
+#[destructure(lifetime = "a")]
+pub struct Book<'a> {
+    name: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use destructure::DestructureRef;
+
+#[derive(DestructureRef)]
+#[destructure(doc_hidden, into_iter)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
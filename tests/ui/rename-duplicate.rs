@@ -0,0 +1,10 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+pub struct Book {
+    title: String,
+    #[destructure(rename = "title")]
+    name: String,
+}
+
+fn main() {}
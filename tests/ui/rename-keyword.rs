@@ -0,0 +1,10 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+pub struct Book {
+    id: String,
+    #[destructure(rename = "match")]
+    name: String,
+}
+
+fn main() {}
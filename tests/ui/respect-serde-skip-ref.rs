@@ -0,0 +1,17 @@
+mod user {
+    use destructure::DestructureRef;
+    use serde::Serialize;
+
+    #[derive(Serialize, DestructureRef)]
+    #[destructure(respect_serde_skip)]
+    pub struct User {
+        pub name: String,
+        #[serde(skip)]
+        pub password: String,
+    }
+}
+
+fn main() {
+    let user = user::User { name: "name".to_string(), password: "secret".to_string() };
+    let _ = user.as_destruct().password;
+}
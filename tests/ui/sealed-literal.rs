@@ -0,0 +1,13 @@
+mod book {
+    use destructure::Destructure;
+
+    #[derive(Destructure)]
+    #[destructure(sealed)]
+    pub struct Book {
+        id: String,
+    }
+}
+
+fn main() {
+    let _ = book::DestructBook { id: "123456789-abc".to_string() }.freeze();
+}
@@ -0,0 +1,10 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+pub struct Book {
+    id: String,
+    #[destructure(skip_if)]
+    name: Option<String>,
+}
+
+fn main() {}
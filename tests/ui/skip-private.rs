@@ -0,0 +1,16 @@
+mod domain {
+    use destructure::{Destructure, DestructureRef};
+
+    #[derive(Destructure, DestructureRef)]
+    pub struct User {
+        pub id: String,
+        #[destructure(skip)]
+        pub password: String,
+    }
+}
+
+fn main() {
+    let user = domain::User { id: "123456789-abc".to_string(), password: "secret".to_string() };
+    let _ = user.as_destruct().password;
+    let _ = user.into_destruct().password;
+}
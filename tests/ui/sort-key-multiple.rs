@@ -0,0 +1,12 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+pub struct Book {
+    id: String,
+    #[destructure(sort_key)]
+    created_at: u64,
+    #[destructure(sort_key)]
+    updated_at: u64,
+}
+
+fn main() {}
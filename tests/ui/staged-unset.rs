@@ -0,0 +1,14 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(staged(required(name, author)))]
+pub struct Book {
+    name: String,
+    author: String,
+}
+
+fn main() {
+    let book = Book { name: "name".to_string(), author: "author".to_string() };
+
+    let _ = book.into_staged().set_name("new name".to_string()).freeze();
+}
@@ -0,0 +1,10 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(transparent)]
+pub struct Book {
+    id: String,
+    name: String,
+}
+
+fn main() {}
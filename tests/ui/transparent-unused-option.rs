@@ -0,0 +1,7 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(transparent, debug)]
+pub struct Id(u64);
+
+fn main() {}
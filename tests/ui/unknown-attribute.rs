@@ -0,0 +1,11 @@
+use destructure::Destructure;
+
+#[derive(Destructure)]
+#[destructure(visibility = "pub(crate)")]
+pub struct Book {
+    id: String,
+    #[destructure(into = "String")]
+    name: String,
+}
+
+fn main() {}
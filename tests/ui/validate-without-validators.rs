@@ -0,0 +1,13 @@
+use destructure::Destructure;
+
+pub fn not_empty(value: &str) -> Result<(), String> {
+    if value.is_empty() { Err("must not be empty".to_string()) } else { Ok(()) }
+}
+
+#[derive(Destructure)]
+pub struct SignUp {
+    #[destructure(validate = "not_empty")]
+    name: String,
+}
+
+fn main() {}
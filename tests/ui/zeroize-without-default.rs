@@ -0,0 +1,19 @@
+use destructure::Destructure;
+use zeroize::Zeroize;
+
+pub struct Key([u8; 32]);
+
+impl Zeroize for Key {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[derive(Destructure)]
+pub struct Account {
+    name: String,
+    #[destructure(zeroize)]
+    key: Key,
+}
+
+fn main() {}